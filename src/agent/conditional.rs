@@ -3,12 +3,17 @@
 //! This module provides Python bindings for conditional routing agents:
 //! - `ConditionalAgent` - Rule-based conditional routing
 //! - `LlmConditionalAgent` - LLM-powered intelligent routing
+//! - `FallbackAgent` - Ordered fallbacks on error
 
-use adk_core::{Agent, Content, Event, EventStream, InvocationContext, Llm, LlmRequest, Part};
+use adk_core::{
+    Agent, Content, Event, EventStream, InvocationContext, Llm, LlmRequest, Part, Tool,
+    ToolContext,
+};
 use async_stream::stream;
 use async_trait::async_trait;
 use futures::StreamExt;
 use pyo3::prelude::*;
+use serde_json::json;
 use std::collections::HashMap;
 use std::sync::Arc;
 
@@ -27,25 +32,56 @@ struct PythonConditionFn {
 unsafe impl Send for PythonConditionFn {}
 unsafe impl Sync for PythonConditionFn {}
 
+impl Clone for PythonConditionFn {
+    fn clone(&self) -> Self {
+        Python::with_gil(|py| Self {
+            condition: self.condition.clone_ref(py),
+        })
+    }
+}
+
 impl PythonConditionFn {
-    fn evaluate(&self, ctx: &dyn InvocationContext) -> bool {
+    /// Evaluate the predicate against the invocation context.
+    ///
+    /// Supports both plain (`def predicate(ctx) -> bool`) and `async def`
+    /// predicates - a coroutine result is driven with `asyncio.run`. Any
+    /// Python exception is surfaced to the caller instead of being
+    /// swallowed into `false`.
+    fn evaluate(&self, ctx: &dyn InvocationContext) -> Result<bool, String> {
         Python::with_gil(|py| {
             let py_ctx = PyInvocationContext::from_invocation_context(ctx);
-            match self.condition.call1(py, (py_ctx,)) {
-                Ok(result) => result.extract::<bool>(py).unwrap_or(false),
-                Err(_) => false,
-            }
+            let result = self
+                .condition
+                .call1(py, (py_ctx,))
+                .map_err(|e| e.to_string())?
+                .into_bound(py);
+
+            let asyncio = py.import_bound("asyncio").map_err(|e| e.to_string())?;
+            let is_coro = asyncio
+                .call_method1("iscoroutine", (&result,))
+                .and_then(|v| v.is_truthy())
+                .map_err(|e| e.to_string())?;
+
+            let result = if is_coro {
+                asyncio
+                    .call_method1("run", (&result,))
+                    .map_err(|e| e.to_string())?
+            } else {
+                result
+            };
+
+            result.extract::<bool>().map_err(|e| e.to_string())
         })
     }
 }
 
-/// Internal Rust agent that wraps Python condition
+/// Internal Rust agent that wraps an ordered list of (predicate, agent)
+/// cases, falling through to a default agent when no predicate matches.
 struct PythonConditionalAgent {
     name: String,
     description: String,
-    condition: PythonConditionFn,
-    if_agent: Arc<dyn Agent>,
-    else_agent: Option<Arc<dyn Agent>>,
+    cases: Vec<(PythonConditionFn, Arc<dyn Agent>)>,
+    default_agent: Option<Arc<dyn Agent>>,
 }
 
 #[async_trait]
@@ -63,28 +99,49 @@ impl Agent for PythonConditionalAgent {
     }
 
     async fn run(&self, ctx: Arc<dyn InvocationContext>) -> adk_core::Result<EventStream> {
-        // Evaluate condition synchronously (it's a simple Python call)
-        let condition_result = {
-            let ctx_ref = ctx.as_ref();
-            self.condition.evaluate(ctx_ref)
-        };
+        let invocation_id = ctx.invocation_id().to_string();
 
-        let agent = if condition_result {
-            self.if_agent.clone()
-        } else if let Some(else_agent) = &self.else_agent {
-            else_agent.clone()
-        } else {
-            return Ok(Box::pin(futures::stream::empty()));
-        };
+        for (condition, agent) in &self.cases {
+            let condition = condition.clone();
+            let ctx_for_eval = ctx.clone();
 
-        agent.run(ctx).await
+            let evaluated = tokio::task::spawn_blocking(move || {
+                condition.evaluate(ctx_for_eval.as_ref())
+            })
+            .await
+            .map_err(|e| adk_core::AdkError::Agent(format!("predicate task failed: {e}")))?;
+
+            match evaluated {
+                Ok(true) => return agent.run(ctx).await,
+                Ok(false) => continue,
+                Err(e) => {
+                    let mut error_event = Event::new(&invocation_id);
+                    error_event.author = self.name.clone();
+                    error_event.llm_response.content = Some(
+                        Content::new("model")
+                            .with_text(format!("Predicate raised an error: {}", e)),
+                    );
+                    return Ok(Box::pin(futures::stream::once(async move {
+                        Ok(error_event)
+                    })));
+                }
+            }
+        }
+
+        if let Some(default_agent) = &self.default_agent {
+            return default_agent.run(ctx).await;
+        }
+
+        Ok(Box::pin(futures::stream::empty()))
     }
 }
 
 /// Rule-based conditional routing agent.
 ///
-/// Routes execution to one of two agents based on a Python condition function
-/// that evaluates session state, flags, or other deterministic criteria.
+/// Routes execution to one of several agents based on Python predicates
+/// that evaluate session state, flags, or other deterministic criteria.
+/// Predicates may be plain functions or `async def` coroutines, and are
+/// tried in order - the first to return `True` wins.
 ///
 /// For LLM-based intelligent routing, use `LlmConditionalAgent` instead.
 #[pyclass(name = "ConditionalAgent")]
@@ -94,11 +151,11 @@ pub struct PyConditionalAgent {
 
 #[pymethods]
 impl PyConditionalAgent {
-    /// Create a new ConditionalAgent.
+    /// Create a new two-way ConditionalAgent.
     ///
     /// Args:
     ///     name: The agent name
-    ///     condition: A function that takes InvocationContext and returns bool
+    ///     condition: A function (or async function) taking InvocationContext and returning bool
     ///     if_agent: Agent to run when condition is True
     ///     else_agent: Optional agent to run when condition is False
     ///     description: Optional description
@@ -111,18 +168,57 @@ impl PyConditionalAgent {
         else_agent: Option<&Bound<'_, PyAny>>,
         description: Option<String>,
     ) -> PyResult<Self> {
-        // Extract if_agent
         let if_agent_arc = extract_agent_arc(if_agent)?;
-
-        // Extract else_agent if provided
         let else_agent_arc = else_agent.map(extract_agent_arc).transpose()?;
 
         let agent = PythonConditionalAgent {
             name,
             description: description.unwrap_or_default(),
-            condition: PythonConditionFn { condition },
-            if_agent: if_agent_arc,
-            else_agent: else_agent_arc,
+            cases: vec![(PythonConditionFn { condition }, if_agent_arc)],
+            default_agent: else_agent_arc,
+        };
+
+        Ok(Self {
+            inner: Arc::new(agent),
+        })
+    }
+
+    /// Create a multi-way ConditionalAgent, tried in order like a match
+    /// statement.
+    ///
+    /// Args:
+    ///     name: The agent name
+    ///     cases: An ordered list of `(predicate, agent)` tuples
+    ///     default: Optional agent to run when no predicate matches
+    ///     description: Optional description
+    #[staticmethod]
+    #[pyo3(signature = (name, cases, default=None, description=None))]
+    fn match_cases(
+        py: Python<'_>,
+        name: String,
+        cases: Vec<(Py<PyAny>, Py<PyAny>)>,
+        default: Option<&Bound<'_, PyAny>>,
+        description: Option<String>,
+    ) -> PyResult<Self> {
+        if cases.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "ConditionalAgent.match_cases requires at least one case",
+            ));
+        }
+
+        let mut resolved_cases = Vec::with_capacity(cases.len());
+        for (predicate, agent) in cases {
+            let agent_arc = extract_agent_arc(agent.bind(py))?;
+            resolved_cases.push((PythonConditionFn { condition: predicate }, agent_arc));
+        }
+
+        let default_agent = default.map(extract_agent_arc).transpose()?;
+
+        let agent = PythonConditionalAgent {
+            name,
+            description: description.unwrap_or_default(),
+            cases: resolved_cases,
+            default_agent,
         };
 
         Ok(Self {
@@ -149,6 +245,54 @@ impl PyConditionalAgent {
 // LlmConditionalAgent - LLM-powered intelligent routing
 // ============================================================================
 
+/// Synthetic tool offered to the model in structured-routing mode.
+///
+/// Its only purpose is to carry an enum-constrained `route` parameter back
+/// as a `Part::FunctionCall`, so routing can be read directly from the
+/// arguments instead of parsed out of free text. It is never executed.
+struct SelectRouteTool {
+    routes: Vec<String>,
+}
+
+#[async_trait]
+impl Tool for SelectRouteTool {
+    fn name(&self) -> &str {
+        "select_route"
+    }
+
+    fn description(&self) -> &str {
+        "Select the route that best matches the user's input."
+    }
+
+    fn parameters_schema(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "route": {
+                    "type": "string",
+                    "enum": self.routes,
+                    "description": "The matching route key"
+                }
+            },
+            "required": ["route"],
+        }))
+    }
+
+    async fn execute(
+        &self,
+        _ctx: Arc<dyn ToolContext>,
+        _args: serde_json::Value,
+    ) -> adk_core::Result<serde_json::Value> {
+        Ok(serde_json::Value::Null)
+    }
+}
+
+/// Temperature used for the extra `generate_content` calls issued by
+/// self-consistency voting (see `PythonLlmConditionalAgent::samples`).
+/// Single-shot classification leaves `config` unset and defers to the
+/// model's own default instead.
+const SELF_CONSISTENCY_TEMPERATURE: f32 = 0.7;
+
 /// Internal Rust agent for LLM-based routing
 struct PythonLlmConditionalAgent {
     name: String,
@@ -156,7 +300,95 @@ struct PythonLlmConditionalAgent {
     model: Arc<dyn Llm>,
     instruction: String,
     routes: HashMap<String, Arc<dyn Agent>>,
+    route_order: Vec<String>,
     default_agent: Option<Arc<dyn Agent>>,
+    structured: bool,
+    route_from_state: Option<String>,
+    samples: usize,
+}
+
+impl PythonLlmConditionalAgent {
+    /// Issue a single classification `generate_content` call and return the
+    /// structured route selection (if any) alongside the normalized,
+    /// lower-cased free-text response.
+    async fn classify_once(
+        model: &Arc<dyn Llm>,
+        instruction: &str,
+        user_text: &str,
+        route_keys: &[String],
+        structured: bool,
+        config: Option<adk_core::GenerateContentConfig>,
+    ) -> adk_core::Result<(Option<String>, String)> {
+        let classification_prompt = if structured {
+            format!(
+                "{}\n\nUser input: {}\n\nCall the select_route tool with the matching route.",
+                instruction, user_text
+            )
+        } else {
+            format!("{}\n\nUser input: {}", instruction, user_text)
+        };
+
+        let mut tools: HashMap<String, Arc<dyn Tool>> = HashMap::new();
+        if structured {
+            tools.insert(
+                "select_route".to_string(),
+                Arc::new(SelectRouteTool {
+                    routes: route_keys.to_vec(),
+                }) as Arc<dyn Tool>,
+            );
+        }
+
+        let request = LlmRequest {
+            model: model.name().to_string(),
+            contents: vec![Content::new("user").with_text(&classification_prompt)],
+            tools,
+            config,
+        };
+
+        let mut response_stream = model.generate_content(request, false).await?;
+
+        let mut classification = String::new();
+        let mut selected_route: Option<String> = None;
+        while let Some(chunk_result) = response_stream.next().await {
+            let chunk = chunk_result?;
+            if let Some(content) = chunk.content {
+                for part in content.parts {
+                    match part {
+                        Part::Text { text } => classification.push_str(&text),
+                        Part::FunctionCall { name, args, .. }
+                            if structured && name == "select_route" =>
+                        {
+                            if let Some(route) = args.get("route").and_then(|v| v.as_str()) {
+                                selected_route = Some(route.to_string());
+                            }
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        Ok((selected_route, classification.trim().to_lowercase()))
+    }
+
+    /// Resolve a single classification attempt to a registered route key:
+    /// prefer the structured function-call selection, falling back to
+    /// substring matching on the free text.
+    fn resolve_route_key(
+        selected_route: &Option<String>,
+        classification: &str,
+        routes: &HashMap<String, Arc<dyn Agent>>,
+    ) -> Option<String> {
+        if let Some(route) = selected_route {
+            if routes.contains_key(route) {
+                return Some(route.clone());
+            }
+        }
+        routes
+            .keys()
+            .find(|label| classification.contains(label.as_str()))
+            .cloned()
+    }
 }
 
 #[async_trait]
@@ -174,12 +406,42 @@ impl Agent for PythonLlmConditionalAgent {
     }
 
     async fn run(&self, ctx: Arc<dyn InvocationContext>) -> adk_core::Result<EventStream> {
+        // Runtime override: skip the classification call entirely when the
+        // caller has pre-selected a route via session state.
+        if let Some(state_key) = &self.route_from_state {
+            let forced_route = ctx
+                .session()
+                .state()
+                .all()
+                .get(state_key)
+                .and_then(|v| v.as_str())
+                .map(|s| s.to_lowercase());
+
+            if let Some(route) = forced_route.and_then(|r| self.routes.contains_key(&r).then_some(r)) {
+                let invocation_id = ctx.invocation_id().to_string();
+                let mut routing_event = Event::new(&invocation_id);
+                routing_event.author = self.name.clone();
+                routing_event.llm_response.content = Some(Content::new("model").with_text(
+                    format!("[Routing to: {} (forced via state key '{}')]", route, state_key),
+                ));
+
+                let agent = self.routes.get(&route).cloned().expect("route checked above");
+                let agent_stream = agent.run(ctx.clone()).await?;
+                return Ok(Box::pin(
+                    futures::stream::once(async move { Ok(routing_event) }).chain(agent_stream),
+                ));
+            }
+        }
+
         let model = self.model.clone();
         let instruction = self.instruction.clone();
         let routes = self.routes.clone();
+        let route_order = self.route_order.clone();
         let default_agent = self.default_agent.clone();
         let invocation_id = ctx.invocation_id().to_string();
         let agent_name = self.name.clone();
+        let structured = self.structured;
+        let samples = self.samples.max(1);
 
         let s = stream! {
             // Build classification request
@@ -189,65 +451,105 @@ impl Agent for PythonLlmConditionalAgent {
                 .collect::<Vec<_>>()
                 .join(" ");
 
-            let classification_prompt = format!(
-                "{}\n\nUser input: {}",
-                instruction,
-                user_text
-            );
+            let route_keys: Vec<String> = routes.keys().cloned().collect();
 
-            let request = LlmRequest {
-                model: model.name().to_string(),
-                contents: vec![Content::new("user").with_text(&classification_prompt)],
-                tools: HashMap::new(),
-                config: None,
-            };
+            // Classification, possibly self-consistency voting: at `samples
+            // == 1` this reduces to the previous single-call behavior; for
+            // `samples > 1` we issue that many independent calls at a
+            // temperature > 0 and take a majority vote over the resolved
+            // route keys, breaking ties by earliest-registered route.
+            let (routing_label, target_agent) = if samples == 1 {
+                let (selected_route, classification) = match Self::classify_once(
+                    &model, &instruction, &user_text, &route_keys, structured, None,
+                ).await {
+                    Ok(v) => v,
+                    Err(e) => {
+                        yield Err(e);
+                        return;
+                    }
+                };
 
-            // Call LLM for classification
-            let mut response_stream = match model.generate_content(request, false).await {
-                Ok(stream) => stream,
-                Err(e) => {
-                    yield Err(e);
-                    return;
+                let route_key = Self::resolve_route_key(&selected_route, &classification, &routes);
+                let label = route_key.clone().unwrap_or(classification);
+                let agent = route_key.and_then(|key| routes.get(&key).cloned()).or_else(|| default_agent.clone());
+                (label, agent)
+            } else {
+                let mut attempts = Vec::with_capacity(samples);
+                for _ in 0..samples {
+                    let config = Some(adk_core::GenerateContentConfig {
+                        temperature: Some(SELF_CONSISTENCY_TEMPERATURE),
+                        top_p: None,
+                        top_k: None,
+                        max_output_tokens: None,
+                        response_schema: None,
+                    });
+                    attempts.push(Self::classify_once(
+                        &model, &instruction, &user_text, &route_keys, structured, config,
+                    ));
                 }
-            };
+                let results = futures::future::join_all(attempts).await;
 
-            // Collect classification response
-            let mut classification = String::new();
-            while let Some(chunk_result) = response_stream.next().await {
-                match chunk_result {
-                    Ok(chunk) => {
-                        if let Some(content) = chunk.content {
-                            for part in content.parts {
-                                if let Part::Text { text } = part {
-                                    classification.push_str(&text);
-                                }
+                let mut tally: HashMap<String, usize> = HashMap::new();
+                let mut last_error = None;
+                for result in results {
+                    match result {
+                        Ok((selected_route, classification)) => {
+                            if let Some(key) = Self::resolve_route_key(&selected_route, &classification, &routes) {
+                                *tally.entry(key).or_insert(0) += 1;
                             }
                         }
+                        Err(e) => last_error = Some(e),
                     }
-                    Err(e) => {
+                }
+
+                if tally.is_empty() {
+                    if let Some(e) = last_error {
                         yield Err(e);
                         return;
                     }
                 }
-            }
 
-            // Normalize classification
-            let classification = classification.trim().to_lowercase();
+                // Earliest-registered route wins ties: walk route_order and
+                // only replace the current winner on a strictly higher vote
+                // count.
+                let mut winner: Option<(String, usize)> = None;
+                for key in &route_order {
+                    if let Some(&count) = tally.get(key) {
+                        let replace = match &winner {
+                            Some((_, best)) => count > *best,
+                            None => true,
+                        };
+                        if replace {
+                            winner = Some((key.clone(), count));
+                        }
+                    }
+                }
+
+                let votes = route_order.iter()
+                    .filter_map(|key| tally.get(key).map(|count| format!("{}: {}", key, count)))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+
+                match winner {
+                    Some((key, _)) => {
+                        let agent = routes.get(&key).cloned();
+                        (format!("{} (votes: {{{}}})", key, votes), agent)
+                    }
+                    None => (
+                        format!("no majority (votes: {{{}}})", votes),
+                        default_agent.clone(),
+                    ),
+                }
+            };
 
             // Emit routing event
             let mut routing_event = Event::new(&invocation_id);
             routing_event.author = agent_name.clone();
             routing_event.llm_response.content = Some(
-                Content::new("model").with_text(format!("[Routing to: {}]", classification))
+                Content::new("model").with_text(format!("[Routing to: {}]", routing_label))
             );
             yield Ok(routing_event);
 
-            // Find matching route
-            let target_agent = routes.iter()
-                .find(|(label, _)| classification.contains(label.as_str()))
-                .map(|(_, agent)| agent.clone())
-                .or(default_agent);
-
             // Execute target agent
             if let Some(agent) = target_agent {
                 match agent.run(ctx.clone()).await {
@@ -267,7 +569,7 @@ impl Agent for PythonLlmConditionalAgent {
                 error_event.llm_response.content = Some(
                     Content::new("model").with_text(format!(
                         "No route found for classification '{}'. Available routes: {:?}",
-                        classification,
+                        routing_label,
                         routes.keys().collect::<Vec<_>>()
                     ))
                 );
@@ -320,7 +622,11 @@ pub struct PyLlmConditionalAgentBuilder {
     model: Arc<dyn Llm>,
     instruction: Option<String>,
     routes: HashMap<String, Arc<dyn Agent>>,
+    route_order: Vec<String>,
     default_agent: Option<Arc<dyn Agent>>,
+    structured: bool,
+    route_from_state: Option<String>,
+    samples: usize,
 }
 
 impl PyLlmConditionalAgentBuilder {
@@ -331,7 +637,11 @@ impl PyLlmConditionalAgentBuilder {
             model,
             instruction: None,
             routes: HashMap::new(),
+            route_order: Vec::new(),
             default_agent: None,
+            structured: false,
+            route_from_state: None,
+            samples: 1,
         }
     }
 }
@@ -363,7 +673,11 @@ impl PyLlmConditionalAgentBuilder {
         agent: &Bound<'a, PyAny>,
     ) -> PyResult<PyRefMut<'a, Self>> {
         let agent_arc = extract_agent_arc(agent)?;
-        slf.routes.insert(label.to_lowercase(), agent_arc);
+        let key = label.to_lowercase();
+        if !slf.routes.contains_key(&key) {
+            slf.route_order.push(key.clone());
+        }
+        slf.routes.insert(key, agent_arc);
         Ok(slf)
     }
 
@@ -377,6 +691,54 @@ impl PyLlmConditionalAgentBuilder {
         Ok(slf)
     }
 
+    /// Opt into structured function-calling routing.
+    ///
+    /// When enabled, the classification request offers a single synthetic
+    /// `select_route` tool with an enum parameter constrained to the
+    /// registered route keys, and routing is read directly from the
+    /// `Part::FunctionCall` arguments instead of substring-matching free
+    /// text. Falls back to the substring match if the model doesn't call
+    /// the tool.
+    fn structured(mut slf: PyRefMut<'_, Self>, enabled: bool) -> PyRefMut<'_, Self> {
+        slf.structured = enabled;
+        slf
+    }
+
+    /// Read a forced route from session state before falling back to the LLM.
+    ///
+    /// Inspired by LangChain's configurable-alternatives pattern: at the
+    /// start of `run`, the agent checks `state_key` in the invocation's
+    /// session state. If it holds a value matching a registered route key,
+    /// execution is dispatched directly to that route's agent - no
+    /// `generate_content` call is made. When the key is absent or doesn't
+    /// match a route, classification proceeds as normal. This lets
+    /// deterministic callers, tests, and upstream agents pin routing
+    /// decisions, saving a model round-trip and its cost.
+    fn route_from_state(mut slf: PyRefMut<'_, Self>, state_key: String) -> PyRefMut<'_, Self> {
+        slf.route_from_state = Some(state_key);
+        slf
+    }
+
+    /// Enable self-consistency voting over `n` independent classification
+    /// calls.
+    ///
+    /// Instead of a single `generate_content` call, issues `n` calls at a
+    /// temperature > 0, resolves each to a route key with the same matching
+    /// logic as single-shot routing, and routes to the key with the most
+    /// votes - ties broken by whichever route was registered first. The
+    /// routing event records the vote distribution so callers can see
+    /// classifier confidence. `n == 1` (the default) keeps the original
+    /// single-call behavior.
+    fn samples(mut slf: PyRefMut<'_, Self>, n: usize) -> PyResult<PyRefMut<'_, Self>> {
+        if n == 0 {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "samples must be at least 1",
+            ));
+        }
+        slf.samples = n;
+        Ok(slf)
+    }
+
     /// Build the LlmConditionalAgent.
     fn build(&self) -> PyResult<PyLlmConditionalAgent> {
         let instruction = self.instruction.clone().ok_or_else(|| {
@@ -397,7 +759,11 @@ impl PyLlmConditionalAgentBuilder {
             model: self.model.clone(),
             instruction,
             routes: self.routes.clone(),
+            route_order: self.route_order.clone(),
             default_agent: self.default_agent.clone(),
+            structured: self.structured,
+            route_from_state: self.route_from_state.clone(),
+            samples: self.samples,
         };
 
         Ok(PyLlmConditionalAgent {
@@ -406,12 +772,241 @@ impl PyLlmConditionalAgentBuilder {
     }
 }
 
+// ============================================================================
+// FallbackAgent - ordered fallbacks on error
+// ============================================================================
+
+/// Internal wrapper for an optional Python predicate over an error string.
+struct PythonErrorPredicate {
+    predicate: Py<PyAny>,
+}
+
+unsafe impl Send for PythonErrorPredicate {}
+unsafe impl Sync for PythonErrorPredicate {}
+
+impl Clone for PythonErrorPredicate {
+    fn clone(&self) -> Self {
+        Python::with_gil(|py| Self {
+            predicate: self.predicate.clone_ref(py),
+        })
+    }
+}
+
+impl PythonErrorPredicate {
+    /// Evaluate the predicate against an error string.
+    ///
+    /// Supports both plain (`def predicate(error: str) -> bool`) and
+    /// `async def` predicates, mirroring `PythonConditionFn::evaluate`.
+    fn evaluate(&self, error: &str) -> Result<bool, String> {
+        Python::with_gil(|py| {
+            let result = self
+                .predicate
+                .call1(py, (error,))
+                .map_err(|e| e.to_string())?
+                .into_bound(py);
+
+            let asyncio = py.import_bound("asyncio").map_err(|e| e.to_string())?;
+            let is_coro = asyncio
+                .call_method1("iscoroutine", (&result,))
+                .and_then(|v| v.is_truthy())
+                .map_err(|e| e.to_string())?;
+
+            let result = if is_coro {
+                asyncio
+                    .call_method1("run", (&result,))
+                    .map_err(|e| e.to_string())?
+            } else {
+                result
+            };
+
+            result.extract::<bool>().map_err(|e| e.to_string())
+        })
+    }
+}
+
+/// Internal Rust agent that runs a primary agent and, on failure, retries an
+/// ordered list of fallback agents against the same `InvocationContext`.
+struct PythonFallbackAgent {
+    name: String,
+    description: String,
+    primary: Arc<dyn Agent>,
+    fallbacks: Vec<Arc<dyn Agent>>,
+    should_fallback: Option<PythonErrorPredicate>,
+}
+
+impl PythonFallbackAgent {
+    /// Run a single candidate agent to completion, collecting its events.
+    ///
+    /// Returns `Err` with a diagnostic string if the agent could not even be
+    /// started, or if its stream produced an error event or no events at
+    /// all - any of which counts as a failed attempt for fallback purposes.
+    async fn try_agent(
+        agent: &Arc<dyn Agent>,
+        ctx: Arc<dyn InvocationContext>,
+    ) -> Result<Vec<Event>, String> {
+        let stream = agent.run(ctx).await.map_err(|e| e.to_string())?;
+        let results: Vec<adk_core::Result<Event>> = stream.collect().await;
+
+        if results.is_empty() {
+            return Err(format!("agent '{}' produced no events", agent.name()));
+        }
+
+        let mut events = Vec::with_capacity(results.len());
+        for result in results {
+            match result {
+                Ok(event) => events.push(event),
+                Err(e) => return Err(e.to_string()),
+            }
+        }
+        Ok(events)
+    }
+
+    /// Whether a failure should move on to the next fallback, per the
+    /// configured predicate. Defaults to always falling back. Runs the
+    /// (potentially blocking) Python predicate on a blocking thread, same as
+    /// `PythonConditionFn::evaluate` is driven elsewhere in this module.
+    async fn should_retry(&self, error: String) -> bool {
+        let Some(predicate) = self.should_fallback.clone() else {
+            return true;
+        };
+        tokio::task::spawn_blocking(move || predicate.evaluate(&error).unwrap_or(true))
+            .await
+            .unwrap_or(true)
+    }
+}
+
+#[async_trait]
+impl Agent for PythonFallbackAgent {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn sub_agents(&self) -> &[Arc<dyn Agent>] {
+        &[]
+    }
+
+    async fn run(&self, ctx: Arc<dyn InvocationContext>) -> adk_core::Result<EventStream> {
+        let invocation_id = ctx.invocation_id().to_string();
+        let candidates = std::iter::once(&self.primary).chain(self.fallbacks.iter());
+        let mut last_error = String::new();
+
+        for (idx, agent) in candidates.enumerate() {
+            match Self::try_agent(agent, ctx.clone()).await {
+                Ok(events) => {
+                    if idx == 0 {
+                        return Ok(Box::pin(futures::stream::iter(events.into_iter().map(Ok))));
+                    }
+
+                    let mut diagnostic = Event::new(&invocation_id);
+                    diagnostic.author = self.name.clone();
+                    diagnostic.llm_response.content = Some(
+                        Content::new("model")
+                            .with_text(format!("[Fallback succeeded: {}]", agent.name())),
+                    );
+                    let mut out = vec![Ok(diagnostic)];
+                    out.extend(events.into_iter().map(Ok));
+                    return Ok(Box::pin(futures::stream::iter(out)));
+                }
+                Err(error) => {
+                    let retry = self.should_retry(error.clone()).await;
+                    last_error = error;
+                    if !retry {
+                        break;
+                    }
+                }
+            }
+        }
+
+        Err(adk_core::AdkError::Agent(format!(
+            "FallbackAgent '{}': all agents failed, last error: {}",
+            self.name, last_error
+        )))
+    }
+}
+
+/// Agent wrapper that runs a primary agent and transparently retries an
+/// ordered list of fallback agents if the primary errors or produces no
+/// events, modeled on LangChain's runnable-with-fallbacks.
+///
+/// Useful for resilience against flaky model backends without hand-writing
+/// try/except orchestration around agent calls.
+#[pyclass(name = "FallbackAgent")]
+pub struct PyFallbackAgent {
+    pub(crate) inner: Arc<dyn Agent>,
+}
+
+#[pymethods]
+impl PyFallbackAgent {
+    /// Create a new FallbackAgent.
+    ///
+    /// Args:
+    ///     name: The agent name
+    ///     primary: The agent to try first
+    ///     fallbacks: Ordered list of agents to retry, in order, on failure
+    ///     should_fallback: Optional predicate (or async predicate) over the
+    ///         error string deciding whether to continue to the next
+    ///         fallback. Defaults to always falling back.
+    ///     description: Optional description
+    #[new]
+    #[pyo3(signature = (name, primary, fallbacks, should_fallback=None, description=None))]
+    fn new(
+        name: String,
+        primary: &Bound<'_, PyAny>,
+        fallbacks: Vec<Py<PyAny>>,
+        should_fallback: Option<Py<PyAny>>,
+        description: Option<String>,
+    ) -> PyResult<Self> {
+        if fallbacks.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "FallbackAgent requires at least one fallback agent",
+            ));
+        }
+
+        let py = primary.py();
+        let primary_arc = extract_agent_arc(primary)?;
+        let mut fallback_arcs = Vec::with_capacity(fallbacks.len());
+        for fallback in fallbacks {
+            fallback_arcs.push(extract_agent_arc(fallback.bind(py))?);
+        }
+
+        let agent = PythonFallbackAgent {
+            name,
+            description: description.unwrap_or_default(),
+            primary: primary_arc,
+            fallbacks: fallback_arcs,
+            should_fallback: should_fallback.map(|predicate| PythonErrorPredicate { predicate }),
+        };
+
+        Ok(Self {
+            inner: Arc::new(agent),
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn description(&self) -> String {
+        self.inner.description().to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("FallbackAgent(name='{}')", self.name())
+    }
+}
+
 // ============================================================================
 // Helper functions
 // ============================================================================
 
 /// Extract an Arc<dyn Agent> from a Python agent object.
-fn extract_agent_arc(agent: &Bound<'_, PyAny>) -> PyResult<Arc<dyn Agent>> {
+pub(crate) fn extract_agent_arc(agent: &Bound<'_, PyAny>) -> PyResult<Arc<dyn Agent>> {
     use crate::agent::custom::PyCustomAgent;
     use crate::agent::llm::PyLlmAgent;
 
@@ -450,7 +1045,12 @@ fn extract_agent_arc(agent: &Bound<'_, PyAny>) -> PyResult<Arc<dyn Agent>> {
         return Ok(loop_agent.inner.clone() as Arc<dyn Agent>);
     }
 
+    // Try FallbackAgent
+    if let Ok(fallback_agent) = agent.extract::<PyRef<'_, PyFallbackAgent>>() {
+        return Ok(fallback_agent.inner.clone());
+    }
+
     Err(pyo3::exceptions::PyTypeError::new_err(
-        "Expected an agent (LlmAgent, CustomAgent, ConditionalAgent, LlmConditionalAgent, SequentialAgent, ParallelAgent, or LoopAgent)",
+        "Expected an agent (LlmAgent, CustomAgent, ConditionalAgent, LlmConditionalAgent, SequentialAgent, ParallelAgent, LoopAgent, or FallbackAgent)",
     ))
 }
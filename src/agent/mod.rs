@@ -8,13 +8,16 @@
 //! - `LoopAgent` - Run agents in a loop
 //! - `ConditionalAgent` - Rule-based conditional routing
 //! - `LlmConditionalAgent` - LLM-powered intelligent routing
+//! - `FallbackAgent` - Ordered fallbacks on error
 
 mod conditional;
 mod custom;
 mod llm;
 pub mod workflow;
 
-pub use conditional::{PyConditionalAgent, PyLlmConditionalAgent, PyLlmConditionalAgentBuilder};
+pub use conditional::{
+    PyConditionalAgent, PyFallbackAgent, PyLlmConditionalAgent, PyLlmConditionalAgentBuilder,
+};
 pub use custom::{PyCustomAgent, PyCustomAgentBuilder};
 pub use llm::{PyLlmAgent, PyLlmAgentBuilder};
 pub use workflow::{PyLoopAgent, PyParallelAgent, PySequentialAgent};
@@ -1,9 +1,22 @@
 //! Workflow agents: Sequential, Parallel, Loop
 
+use adk_core::{Agent, Content, Event, InvocationContext};
+use adk_guardrail::GuardrailSet;
+use async_trait::async_trait;
+use futures::StreamExt;
 use pyo3::prelude::*;
 use std::sync::Arc;
 
-use super::llm::PyLlmAgent;
+use super::conditional::extract_agent_arc;
+use crate::guardrail::PyGuardrailSet;
+
+/// Extract an `Arc<dyn Agent>` from each entry in `agents`, accepting LLM
+/// agents and other workflow agents (or anything else `extract_agent_arc`
+/// recognizes) interchangeably, so orchestration graphs can nest freely -
+/// e.g. a loop whose body is a sequential pipeline of parallel fan-outs.
+fn extract_agent_arcs(agents: Vec<Bound<'_, PyAny>>) -> PyResult<Vec<Arc<dyn adk_core::Agent>>> {
+    agents.iter().map(extract_agent_arc).collect()
+}
 
 /// Executes agents in sequence, one after another
 #[pyclass(name = "SequentialAgent")]
@@ -14,15 +27,12 @@ pub struct PySequentialAgent {
 #[pymethods]
 impl PySequentialAgent {
     #[new]
-    fn new(name: String, agents: Vec<PyRef<'_, PyLlmAgent>>) -> Self {
-        let rust_agents: Vec<Arc<dyn adk_core::Agent>> = agents
-            .iter()
-            .map(|a| a.inner.clone() as Arc<dyn adk_core::Agent>)
-            .collect();
+    fn new(name: String, agents: Vec<Bound<'_, PyAny>>) -> PyResult<Self> {
+        let rust_agents = extract_agent_arcs(agents)?;
 
-        Self {
+        Ok(Self {
             inner: Arc::new(adk_agent::SequentialAgent::new(&name, rust_agents)),
-        }
+        })
     }
 
     #[getter]
@@ -44,15 +54,12 @@ pub struct PyParallelAgent {
 #[pymethods]
 impl PyParallelAgent {
     #[new]
-    fn new(name: String, agents: Vec<PyRef<'_, PyLlmAgent>>) -> Self {
-        let rust_agents: Vec<Arc<dyn adk_core::Agent>> = agents
-            .iter()
-            .map(|a| a.inner.clone() as Arc<dyn adk_core::Agent>)
-            .collect();
+    fn new(name: String, agents: Vec<Bound<'_, PyAny>>) -> PyResult<Self> {
+        let rust_agents = extract_agent_arcs(agents)?;
 
-        Self {
+        Ok(Self {
             inner: Arc::new(adk_agent::ParallelAgent::new(&name, rust_agents)),
-        }
+        })
     }
 
     #[getter]
@@ -65,27 +72,208 @@ impl PyParallelAgent {
     }
 }
 
+/// Python predicate evaluated against a loop iteration's output content.
+///
+/// Supports both plain (`def predicate(content: Content | None) -> bool`)
+/// and `async def` predicates, mirroring
+/// `conditional::PythonErrorPredicate::evaluate`.
+struct PythonUntilPredicate {
+    predicate: Py<PyAny>,
+}
+
+unsafe impl Send for PythonUntilPredicate {}
+unsafe impl Sync for PythonUntilPredicate {}
+
+impl Clone for PythonUntilPredicate {
+    fn clone(&self) -> Self {
+        Python::with_gil(|py| Self {
+            predicate: self.predicate.clone_ref(py),
+        })
+    }
+}
+
+impl PythonUntilPredicate {
+    fn evaluate(&self, content: Option<Content>) -> Result<bool, String> {
+        Python::with_gil(|py| {
+            let py_content = content.map(crate::types::PyContent::from);
+            let result = self
+                .predicate
+                .call1(py, (py_content,))
+                .map_err(|e| e.to_string())?
+                .into_bound(py);
+
+            let asyncio = py.import_bound("asyncio").map_err(|e| e.to_string())?;
+            let is_coro = asyncio
+                .call_method1("iscoroutine", (&result,))
+                .and_then(|v| v.is_truthy())
+                .map_err(|e| e.to_string())?;
+
+            let result = if is_coro {
+                asyncio
+                    .call_method1("run", (&result,))
+                    .map_err(|e| e.to_string())?
+            } else {
+                result
+            };
+
+            result.extract::<bool>().map_err(|e| e.to_string())
+        })
+    }
+}
+
+/// A loop's pluggable exit condition, evaluated after each iteration.
+enum LoopUntil {
+    Guardrails(GuardrailSet),
+    Predicate(PythonUntilPredicate),
+}
+
+/// Internal Rust agent that drives a loop body through up to
+/// `max_iterations` passes, stopping early once `until` is satisfied.
+/// Used in place of `adk_agent::LoopAgent` whenever an `until` condition is
+/// configured, since that crate only supports a fixed iteration count.
+struct PythonLoopAgent {
+    name: String,
+    body: Vec<Arc<dyn Agent>>,
+    max_iterations: u32,
+    until: LoopUntil,
+}
+
+impl PythonLoopAgent {
+    /// Run every agent in the loop body, in order, against the same
+    /// `InvocationContext`, collecting all emitted events - mirrors
+    /// `conditional::PythonFallbackAgent::try_agent`.
+    async fn run_body(
+        body: &[Arc<dyn Agent>],
+        ctx: Arc<dyn InvocationContext>,
+    ) -> adk_core::Result<Vec<Event>> {
+        let mut events = Vec::new();
+        for agent in body {
+            let stream = agent.run(ctx.clone()).await?;
+            let results: Vec<adk_core::Result<Event>> = stream.collect().await;
+            for result in results {
+                events.push(result?);
+            }
+        }
+        Ok(events)
+    }
+
+    /// Whether the latest iteration's output satisfies the stop condition.
+    async fn satisfied(&self, content: Option<Content>) -> bool {
+        match &self.until {
+            LoopUntil::Guardrails(set) => {
+                let Some(content) = content else {
+                    return false;
+                };
+                adk_guardrail::GuardrailExecutor::run(set, &content)
+                    .await
+                    .map(|r| r.passed)
+                    .unwrap_or(false)
+            }
+            LoopUntil::Predicate(predicate) => {
+                let predicate = predicate.clone();
+                tokio::task::spawn_blocking(move || predicate.evaluate(content).unwrap_or(false))
+                    .await
+                    .unwrap_or(false)
+            }
+        }
+    }
+}
+
+#[async_trait]
+impl Agent for PythonLoopAgent {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        ""
+    }
+
+    fn sub_agents(&self) -> &[Arc<dyn Agent>] {
+        &self.body
+    }
+
+    async fn run(&self, ctx: Arc<dyn InvocationContext>) -> adk_core::Result<adk_core::EventStream> {
+        let mut all_events = Vec::new();
+
+        for _ in 0..self.max_iterations.max(1) {
+            let events = Self::run_body(&self.body, ctx.clone()).await?;
+            let last_content = events
+                .iter()
+                .rev()
+                .find_map(|e| e.llm_response.content.clone());
+            let done = self.satisfied(last_content).await;
+            all_events.extend(events);
+            if done {
+                break;
+            }
+        }
+
+        Ok(Box::pin(futures::stream::iter(all_events.into_iter().map(Ok))))
+    }
+}
+
 /// Executes agents in a loop until a condition is met
 #[pyclass(name = "LoopAgent")]
 pub struct PyLoopAgent {
-    pub(crate) inner: Arc<adk_agent::LoopAgent>,
+    pub(crate) inner: Arc<dyn Agent>,
+    max_iterations: u32,
 }
 
 #[pymethods]
 impl PyLoopAgent {
+    /// Create a new LoopAgent.
+    ///
+    /// Args:
+    ///     name: The agent name
+    ///     agents: The loop body, run in sequence each iteration
+    ///     max_iterations: Hard cap on iterations (default 10)
+    ///     until: Optional early-exit condition, checked against the last
+    ///         iteration's output content after each pass - either a
+    ///         `GuardrailSet` (stop once the content passes the set) or a
+    ///         callable, sync or async, taking the output `Content` (or
+    ///         `None`) and returning a bool. Defaults to always running
+    ///         `max_iterations` times.
     #[new]
-    #[pyo3(signature = (name, agents, max_iterations=10))]
-    fn new(name: String, agents: Vec<PyRef<'_, PyLlmAgent>>, max_iterations: u32) -> Self {
-        let rust_agents: Vec<Arc<dyn adk_core::Agent>> = agents
-            .iter()
-            .map(|a| a.inner.clone() as Arc<dyn adk_core::Agent>)
-            .collect();
-
-        Self {
-            inner: Arc::new(
+    #[pyo3(signature = (name, agents, max_iterations=10, until=None))]
+    fn new(
+        name: String,
+        agents: Vec<Bound<'_, PyAny>>,
+        max_iterations: u32,
+        until: Option<Bound<'_, PyAny>>,
+    ) -> PyResult<Self> {
+        let rust_agents = extract_agent_arcs(agents)?;
+
+        let inner: Arc<dyn Agent> = match until {
+            None => Arc::new(
                 adk_agent::LoopAgent::new(&name, rust_agents).with_max_iterations(max_iterations),
             ),
-        }
+            Some(condition) => {
+                let until = if let Ok(guardrails) = condition.extract::<PyGuardrailSet>() {
+                    LoopUntil::Guardrails(guardrails.to_guardrail_set())
+                } else if condition.is_callable() {
+                    LoopUntil::Predicate(PythonUntilPredicate {
+                        predicate: condition.unbind(),
+                    })
+                } else {
+                    return Err(pyo3::exceptions::PyTypeError::new_err(
+                        "until must be a GuardrailSet or a callable taking the output Content and returning bool",
+                    ));
+                };
+
+                Arc::new(PythonLoopAgent {
+                    name,
+                    body: rust_agents,
+                    max_iterations,
+                    until,
+                })
+            }
+        };
+
+        Ok(Self {
+            inner,
+            max_iterations,
+        })
     }
 
     #[getter]
@@ -94,6 +282,10 @@ impl PyLoopAgent {
     }
 
     fn __repr__(&self) -> String {
-        format!("LoopAgent(name='{}', max_iterations=?)", self.name())
+        format!(
+            "LoopAgent(name='{}', max_iterations={})",
+            self.name(),
+            self.max_iterations
+        )
     }
 }
@@ -1,249 +1,2287 @@
-//! Python bindings for artifact services
-
-use adk_artifact::{
-    ArtifactService, DeleteRequest, InMemoryArtifactService, ListRequest, LoadRequest, SaveRequest,
-    VersionsRequest,
-};
-use adk_core::Part;
-use pyo3::prelude::*;
-use pyo3::types::PyBytes;
-use std::sync::Arc;
-
-use crate::types::PyPart;
-
-/// In-memory artifact service for binary data storage
-#[pyclass(name = "InMemoryArtifactService")]
-pub struct PyInMemoryArtifactService {
-    inner: Arc<InMemoryArtifactService>,
-}
-
-#[pymethods]
-impl PyInMemoryArtifactService {
-    #[new]
-    fn new() -> Self {
-        Self {
-            inner: Arc::new(InMemoryArtifactService::new()),
-        }
-    }
-
-    /// Save an artifact (bytes or text)
-    ///
-    /// Args:
-    ///     app_name: Application name
-    ///     user_id: User ID
-    ///     session_id: Session ID
-    ///     file_name: Artifact name (prefix with "user:" for user-scoped)
-    ///     data: Binary data (bytes) or text (str)
-    ///     mime_type: Optional MIME type (defaults to application/octet-stream for bytes)
-    ///     version: Optional version number (auto-increments if not specified)
-    ///
-    /// Returns:
-    ///     Version number of saved artifact
-    #[pyo3(signature = (app_name, user_id, session_id, file_name, data, mime_type=None, version=None))]
-    fn save<'py>(
-        &self,
-        py: Python<'py>,
-        app_name: String,
-        user_id: String,
-        session_id: String,
-        file_name: String,
-        data: Bound<'py, PyAny>,
-        mime_type: Option<String>,
-        version: Option<i64>,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        let inner = self.inner.clone();
-
-        // Convert data to Part
-        let part: Part = if let Ok(bytes) = data.downcast::<PyBytes>() {
-            let bytes_vec = bytes.as_bytes().to_vec();
-            let mime = mime_type.unwrap_or_else(|| "application/octet-stream".to_string());
-            Part::InlineData {
-                mime_type: mime,
-                data: bytes_vec,
-            }
-        } else if let Ok(text) = data.extract::<String>() {
-            Part::Text { text }
-        } else {
-            return Err(pyo3::exceptions::PyTypeError::new_err(
-                "data must be bytes or str",
-            ));
-        };
-
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let request = SaveRequest {
-                app_name,
-                user_id,
-                session_id,
-                file_name,
-                part,
-                version,
-            };
-
-            let response = inner
-                .save(request)
-                .await
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-
-            Ok(response.version)
-        })
-    }
-
-    /// Load an artifact
-    ///
-    /// Args:
-    ///     app_name: Application name
-    ///     user_id: User ID
-    ///     session_id: Session ID
-    ///     file_name: Artifact name
-    ///     version: Optional version (loads latest if not specified)
-    ///
-    /// Returns:
-    ///     Part containing the artifact data
-    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
-    fn load<'py>(
-        &self,
-        py: Python<'py>,
-        app_name: String,
-        user_id: String,
-        session_id: String,
-        file_name: String,
-        version: Option<i64>,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        let inner = self.inner.clone();
-
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let request = LoadRequest {
-                app_name,
-                user_id,
-                session_id,
-                file_name,
-                version,
-            };
-
-            let response = inner
-                .load(request)
-                .await
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-
-            Ok(PyPart::from(response.part))
-        })
-    }
-
-    /// Delete an artifact
-    ///
-    /// Args:
-    ///     app_name: Application name
-    ///     user_id: User ID
-    ///     session_id: Session ID
-    ///     file_name: Artifact name
-    ///     version: Optional version (deletes all versions if not specified)
-    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
-    fn delete<'py>(
-        &self,
-        py: Python<'py>,
-        app_name: String,
-        user_id: String,
-        session_id: String,
-        file_name: String,
-        version: Option<i64>,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        let inner = self.inner.clone();
-
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let request = DeleteRequest {
-                app_name,
-                user_id,
-                session_id,
-                file_name,
-                version,
-            };
-
-            inner
-                .delete(request)
-                .await
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-
-            Ok(())
-        })
-    }
-
-    /// List all artifact names in a session
-    ///
-    /// Args:
-    ///     app_name: Application name
-    ///     user_id: User ID
-    ///     session_id: Session ID
-    ///
-    /// Returns:
-    ///     List of artifact file names
-    #[pyo3(signature = (app_name, user_id, session_id))]
-    fn list<'py>(
-        &self,
-        py: Python<'py>,
-        app_name: String,
-        user_id: String,
-        session_id: String,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        let inner = self.inner.clone();
-
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let request = ListRequest {
-                app_name,
-                user_id,
-                session_id,
-            };
-
-            let response = inner
-                .list(request)
-                .await
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-
-            Ok(response.file_names)
-        })
-    }
-
-    /// Get all versions of an artifact
-    ///
-    /// Args:
-    ///     app_name: Application name
-    ///     user_id: User ID
-    ///     session_id: Session ID
-    ///     file_name: Artifact name
-    ///
-    /// Returns:
-    ///     List of version numbers (descending order)
-    #[pyo3(signature = (app_name, user_id, session_id, file_name))]
-    fn versions<'py>(
-        &self,
-        py: Python<'py>,
-        app_name: String,
-        user_id: String,
-        session_id: String,
-        file_name: String,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        let inner = self.inner.clone();
-
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let request = VersionsRequest {
-                app_name,
-                user_id,
-                session_id,
-                file_name,
-            };
-
-            let response = inner
-                .versions(request)
-                .await
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-
-            Ok(response.versions)
-        })
-    }
-}
-
-impl PyInMemoryArtifactService {
-    /// Get the inner Arc for use in Runner
-    pub fn inner(&self) -> Arc<InMemoryArtifactService> {
-        self.inner.clone()
-    }
-}
+//! Python bindings for artifact services
+//!
+//! `InMemoryArtifactService` vanishes on process exit. The persistent
+//! backends below - `FileSystemArtifactService`, `S3ArtifactService`,
+//! `GcsArtifactService` - all implement the same `ArtifactService` trait
+//! and save/load/delete/list/versions surface, so any of them can be
+//! dropped in without changing call sites. Each one is constructed from a
+//! scheme-prefixed URI (`file://`, `s3://`, `gs://`) and derives its
+//! object key from `app_name/user_id/session_id/file_name/version`.
+//!
+//! `save`/`load` round-trip through a single in-memory `Vec<u8>`/`Part`.
+//! For payloads too large to hold twice in memory, `save_stream`/
+//! `load_stream` drive the same backends through `ArtifactService`'s
+//! streaming methods instead, pulling from and handing back a Python
+//! async iterator of `bytes` one chunk at a time.
+//!
+//! `save_table`/`load_table` give tabular data the same versioned-artifact
+//! treatment via a PyArrow `RecordBatch`/`Table` encoded as an Arrow IPC
+//! stream (`ARROW_STREAM_MIME`), so analysts can load results straight
+//! into Arrow/pandas.
+
+use adk_artifact::{
+    ArtifactService, DeleteRequest, FileSystemArtifactService, GcsArtifactService,
+    InMemoryArtifactService, ListRequest, LoadRequest, LoadStreamRequest, S3ArtifactService,
+    SaveRequest, SaveStreamRequest, VersionsRequest,
+};
+use adk_core::Part;
+use arrow::array::RecordBatch;
+use arrow::pyarrow::{FromPyArrow, ToPyArrow};
+use futures::{Stream, StreamExt};
+use pyo3::prelude::*;
+use pyo3::types::PyBytes;
+use serde::{Deserialize, Serialize};
+use std::os::raw::{c_int, c_void};
+use std::pin::Pin;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::types::PyPart;
+
+/// Store a full snapshot every `SNAPSHOT_INTERVAL` versions when saving in
+/// delta mode, bounding how many change records `load`/`load_history` has
+/// to replay to reach any given version.
+const SNAPSHOT_INTERVAL: i64 = 20;
+
+/// MIME type marking a `Part::InlineData` payload as a JSON-encoded
+/// `TextDeltaEnvelope` rather than a plain artifact.
+const TEXT_DELTA_MIME: &str = "application/vnd.adk.text-delta+json";
+
+/// A single char-indexed edit against the *previous* version's text, in
+/// the same shape as codemp's `TextChange`: `apply(s) = s[..start] +
+/// content + s[end..]` using char (not byte) offsets. `start == end` is a
+/// pure insertion; empty `content` is a pure deletion.
+#[pyclass(name = "TextChange")]
+#[derive(Clone, Serialize, Deserialize)]
+pub struct PyTextChange {
+    /// The version this change produces.
+    #[pyo3(get)]
+    pub version: i64,
+    #[pyo3(get)]
+    pub start: usize,
+    #[pyo3(get)]
+    pub end: usize,
+    #[pyo3(get)]
+    pub content: String,
+}
+
+#[pymethods]
+impl PyTextChange {
+    fn __repr__(&self) -> String {
+        format!(
+            "TextChange(version={}, start={}, end={}, content={:?})",
+            self.version, self.start, self.end, self.content
+        )
+    }
+}
+
+/// The raw change record as stored on disk (no `version` - that's implicit
+/// in which artifact version the envelope was saved under).
+#[derive(Clone, Serialize, Deserialize)]
+struct RawTextChange {
+    start: usize,
+    end: usize,
+    content: String,
+}
+
+/// What a delta-mode text version is stored as: either a full copy (the
+/// first version, or every `SNAPSHOT_INTERVAL`th one) or a change set
+/// against the immediately preceding version.
+#[derive(Serialize, Deserialize)]
+enum TextDeltaEnvelope {
+    Snapshot { text: String },
+    Delta { changes: Vec<RawTextChange> },
+}
+
+enum DecodedText {
+    Snapshot(String),
+    Delta(Vec<RawTextChange>),
+    Plain(Part),
+}
+
+/// Recognize a delta-encoded text payload, falling through to `Plain` for
+/// anything saved outside delta mode.
+fn decode_text_part(part: Part) -> PyResult<DecodedText> {
+    if let Part::InlineData { mime_type, data } = &part {
+        if mime_type == TEXT_DELTA_MIME {
+            let envelope: TextDeltaEnvelope = serde_json::from_slice(data).map_err(|e| {
+                pyo3::exceptions::PyValueError::new_err(format!("corrupt text-delta envelope: {e}"))
+            })?;
+            return Ok(match envelope {
+                TextDeltaEnvelope::Snapshot { text } => DecodedText::Snapshot(text),
+                TextDeltaEnvelope::Delta { changes } => DecodedText::Delta(changes),
+            });
+        }
+    }
+    Ok(DecodedText::Plain(part))
+}
+
+/// Compute the minimal single-span change that turns `prev` into `next`,
+/// by trimming their common char prefix and suffix. Returns no changes
+/// when the two strings are identical - the caller still bumps the
+/// version number for an empty change set.
+fn diff_text(prev: &str, next: &str) -> Vec<RawTextChange> {
+    let prev_chars: Vec<char> = prev.chars().collect();
+    let next_chars: Vec<char> = next.chars().collect();
+    let max_common = prev_chars.len().min(next_chars.len());
+
+    let mut prefix = 0;
+    while prefix < max_common && prev_chars[prefix] == next_chars[prefix] {
+        prefix += 1;
+    }
+
+    let mut suffix = 0;
+    while suffix < max_common - prefix
+        && prev_chars[prev_chars.len() - 1 - suffix] == next_chars[next_chars.len() - 1 - suffix]
+    {
+        suffix += 1;
+    }
+
+    let start = prefix;
+    let end = prev_chars.len() - suffix;
+    let content: String = next_chars[prefix..next_chars.len() - suffix]
+        .iter()
+        .collect();
+
+    if start == end && content.is_empty() {
+        Vec::new()
+    } else {
+        vec![RawTextChange {
+            start,
+            end,
+            content,
+        }]
+    }
+}
+
+/// Apply a set of char-indexed changes to `base`, validating each range
+/// against the base's length. Changes from the same save are sorted and
+/// applied right-to-left so an earlier change's offsets stay valid while
+/// a later one is applied first.
+fn apply_changes(base: &str, changes: &[RawTextChange]) -> PyResult<String> {
+    let mut chars: Vec<char> = base.chars().collect();
+
+    let mut ordered: Vec<&RawTextChange> = changes.iter().collect();
+    ordered.sort_by(|a, b| b.start.cmp(&a.start));
+
+    for change in ordered {
+        if change.start > change.end || change.end > chars.len() {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "text change range {}..{} out of bounds for a {}-char version",
+                change.start,
+                change.end,
+                chars.len()
+            )));
+        }
+        let replacement: Vec<char> = change.content.chars().collect();
+        chars.splice(change.start..change.end, replacement);
+    }
+
+    Ok(chars.into_iter().collect())
+}
+
+/// Reconstruct a delta-encoded text artifact's full contents at `version`
+/// by walking backward to the nearest snapshot, then replaying the change
+/// chain forward.
+async fn reconstruct_text<S: ArtifactService>(
+    inner: &S,
+    app_name: &str,
+    user_id: &str,
+    session_id: &str,
+    file_name: &str,
+    version: i64,
+) -> PyResult<String> {
+    let mut chain: Vec<Vec<RawTextChange>> = Vec::new();
+    let mut current = version;
+
+    loop {
+        let response = inner
+            .load(LoadRequest {
+                app_name: app_name.to_string(),
+                user_id: user_id.to_string(),
+                session_id: session_id.to_string(),
+                file_name: file_name.to_string(),
+                version: Some(current),
+            })
+            .await
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+        match decode_text_part(response.part)? {
+            DecodedText::Snapshot(text) => {
+                let mut result = text;
+                for changes in chain.into_iter().rev() {
+                    result = apply_changes(&result, &changes)?;
+                }
+                return Ok(result);
+            }
+            DecodedText::Delta(changes) => {
+                chain.push(changes);
+                current -= 1;
+                if current < 1 {
+                    return Err(pyo3::exceptions::PyRuntimeError::new_err(
+                        "text artifact history is missing its base snapshot",
+                    ));
+                }
+            }
+            DecodedText::Plain(_) => {
+                return Err(pyo3::exceptions::PyRuntimeError::new_err(
+                    "artifact was not saved in delta mode",
+                ));
+            }
+        }
+    }
+}
+
+/// Load an artifact, transparently reconstructing it if it was saved in
+/// delta mode. Safe to call unconditionally: a plain (non-delta) artifact
+/// is returned unchanged.
+async fn load_decoded<S: ArtifactService>(
+    inner: &S,
+    app_name: String,
+    user_id: String,
+    session_id: String,
+    file_name: String,
+    version: Option<i64>,
+) -> PyResult<Part> {
+    let resolved_version = match version {
+        Some(v) => v,
+        None => {
+            let versions = inner
+                .versions(VersionsRequest {
+                    app_name: app_name.clone(),
+                    user_id: user_id.clone(),
+                    session_id: session_id.clone(),
+                    file_name: file_name.clone(),
+                })
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+                .versions;
+            *versions.iter().max().ok_or_else(|| {
+                pyo3::exceptions::PyRuntimeError::new_err(format!(
+                    "no versions found for artifact '{file_name}'"
+                ))
+            })?
+        }
+    };
+
+    let response = inner
+        .load(LoadRequest {
+            app_name: app_name.clone(),
+            user_id: user_id.clone(),
+            session_id: session_id.clone(),
+            file_name: file_name.clone(),
+            version: Some(resolved_version),
+        })
+        .await
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    match decode_text_part(response.part)? {
+        DecodedText::Plain(part) => Ok(part),
+        DecodedText::Snapshot(text) => Ok(Part::Text { text }),
+        DecodedText::Delta(_) => {
+            let text = reconstruct_text(
+                inner,
+                &app_name,
+                &user_id,
+                &session_id,
+                &file_name,
+                resolved_version,
+            )
+            .await?;
+            Ok(Part::Text { text })
+        }
+    }
+}
+
+/// Save a `Part::Text` artifact in delta mode: the first version (and
+/// every `SNAPSHOT_INTERVAL`th one thereafter) is a full snapshot, every
+/// other version is a change set against its immediate predecessor.
+async fn save_text_delta<S: ArtifactService>(
+    inner: &S,
+    app_name: String,
+    user_id: String,
+    session_id: String,
+    file_name: String,
+    text: String,
+    version: Option<i64>,
+) -> PyResult<i64> {
+    let existing = inner
+        .versions(VersionsRequest {
+            app_name: app_name.clone(),
+            user_id: user_id.clone(),
+            session_id: session_id.clone(),
+            file_name: file_name.clone(),
+        })
+        .await
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .versions;
+
+    let latest = existing.iter().max().copied();
+    let next_version = version.unwrap_or_else(|| latest.unwrap_or(0) + 1);
+
+    let envelope = match latest {
+        Some(prev_version) if next_version % SNAPSHOT_INTERVAL != 0 => {
+            let prev_text = reconstruct_text(
+                inner,
+                &app_name,
+                &user_id,
+                &session_id,
+                &file_name,
+                prev_version,
+            )
+            .await?;
+            TextDeltaEnvelope::Delta {
+                changes: diff_text(&prev_text, &text),
+            }
+        }
+        _ => TextDeltaEnvelope::Snapshot { text },
+    };
+
+    let data = serde_json::to_vec(&envelope).map_err(|e| {
+        pyo3::exceptions::PyValueError::new_err(format!("failed to encode text delta: {e}"))
+    })?;
+    let part = Part::InlineData {
+        mime_type: TEXT_DELTA_MIME.to_string(),
+        data,
+    };
+
+    let response = inner
+        .save(SaveRequest {
+            app_name,
+            user_id,
+            session_id,
+            file_name,
+            part,
+            version: Some(next_version),
+        })
+        .await
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    Ok(response.version)
+}
+
+/// List the change records recorded for a delta-mode text artifact, in
+/// ascending version order. Versions stored as a full snapshot (the first
+/// one, or a periodic one) contribute no entries.
+async fn history_text_delta<S: ArtifactService>(
+    inner: &S,
+    app_name: String,
+    user_id: String,
+    session_id: String,
+    file_name: String,
+) -> PyResult<Vec<PyTextChange>> {
+    let mut versions = inner
+        .versions(VersionsRequest {
+            app_name: app_name.clone(),
+            user_id: user_id.clone(),
+            session_id: session_id.clone(),
+            file_name: file_name.clone(),
+        })
+        .await
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .versions;
+    versions.sort();
+
+    let mut history = Vec::new();
+    for version in versions {
+        let response = inner
+            .load(LoadRequest {
+                app_name: app_name.clone(),
+                user_id: user_id.clone(),
+                session_id: session_id.clone(),
+                file_name: file_name.clone(),
+                version: Some(version),
+            })
+            .await
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+        if let DecodedText::Delta(changes) = decode_text_part(response.part)? {
+            for change in changes {
+                history.push(PyTextChange {
+                    version,
+                    start: change.start,
+                    end: change.end,
+                    content: change.content,
+                });
+            }
+        }
+    }
+
+    Ok(history)
+}
+
+/// Convert Python `bytes` or `str` artifact data into a `Part`.
+fn part_from_data(data: &Bound<'_, PyAny>, mime_type: Option<String>) -> PyResult<Part> {
+    if let Ok(bytes) = data.downcast::<PyBytes>() {
+        let bytes_vec = bytes.as_bytes().to_vec();
+        let mime = mime_type.unwrap_or_else(|| "application/octet-stream".to_string());
+        Ok(Part::InlineData {
+            mime_type: mime,
+            data: bytes_vec,
+        })
+    } else if let Ok(text) = data.extract::<String>() {
+        Ok(Part::Text { text })
+    } else {
+        Err(pyo3::exceptions::PyTypeError::new_err(
+            "data must be bytes or str",
+        ))
+    }
+}
+
+/// Split a scheme-prefixed bucket URI (e.g. `s3://bucket/prefix`) into its
+/// bucket and optional key prefix.
+fn parse_bucket_uri(uri: &str, scheme: &str) -> PyResult<(String, Option<String>)> {
+    let rest = uri.strip_prefix(scheme).ok_or_else(|| {
+        pyo3::exceptions::PyValueError::new_err(format!("expected a '{scheme}' URI, got '{uri}'"))
+    })?;
+    match rest.split_once('/') {
+        Some((bucket, prefix)) if !prefix.is_empty() => {
+            Ok((bucket.to_string(), Some(prefix.to_string())))
+        }
+        _ => Ok((rest.trim_end_matches('/').to_string(), None)),
+    }
+}
+
+/// MIME type for a tabular artifact stored as an Arrow IPC stream.
+const ARROW_STREAM_MIME: &str = "application/vnd.apache.arrow.stream";
+
+/// Serialize a `RecordBatch` to the Arrow IPC stream format, the artifact
+/// byte layout `save_table`/`load_table` store under `ARROW_STREAM_MIME`.
+fn record_batch_to_ipc_bytes(batch: &RecordBatch) -> PyResult<Vec<u8>> {
+    let mut buf = Vec::new();
+    {
+        let mut writer = arrow::ipc::writer::StreamWriter::try_new(&mut buf, &batch.schema())
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+        writer
+            .write(batch)
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+        writer
+            .finish()
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+    }
+    Ok(buf)
+}
+
+/// Deserialize the first `RecordBatch` out of an Arrow IPC stream.
+fn ipc_bytes_to_record_batch(bytes: &[u8]) -> PyResult<RecordBatch> {
+    let mut reader = arrow::ipc::reader::StreamReader::try_new(bytes, None)
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+    reader
+        .next()
+        .transpose()
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .ok_or_else(|| {
+            pyo3::exceptions::PyValueError::new_err("artifact contains no Arrow record batches")
+        })
+}
+
+/// Save pre-encoded Arrow IPC-stream bytes as a versioned artifact.
+async fn save_table_generic<S: ArtifactService>(
+    inner: &S,
+    app_name: String,
+    user_id: String,
+    session_id: String,
+    file_name: String,
+    data: Vec<u8>,
+    version: Option<i64>,
+) -> PyResult<i64> {
+    let request = SaveRequest {
+        app_name,
+        user_id,
+        session_id,
+        file_name,
+        part: Part::InlineData {
+            mime_type: ARROW_STREAM_MIME.to_string(),
+            data,
+        },
+        version,
+    };
+
+    let response = inner
+        .save(request)
+        .await
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    Ok(response.version)
+}
+
+/// Load an Arrow IPC-stream artifact and decode its first `RecordBatch`.
+async fn load_table_generic<S: ArtifactService>(
+    inner: &S,
+    app_name: String,
+    user_id: String,
+    session_id: String,
+    file_name: String,
+    version: Option<i64>,
+) -> PyResult<RecordBatch> {
+    let request = LoadRequest {
+        app_name,
+        user_id,
+        session_id,
+        file_name,
+        version,
+    };
+
+    let response = inner
+        .load(request)
+        .await
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    match response.part {
+        Part::InlineData { data, .. } => ipc_bytes_to_record_batch(&data),
+        _ => Err(pyo3::exceptions::PyTypeError::new_err(
+            "artifact is not an Arrow IPC-stream table",
+        )),
+    }
+}
+
+/// Pull chunks from a Python async iterator of `bytes` on demand, one
+/// `__anext__` at a time, so a `save_stream` caller never has more than one
+/// chunk resident in memory. Ends the stream on `StopAsyncIteration`;
+/// surfaces any other exception as the final item.
+fn python_byte_stream(
+    chunks: Py<PyAny>,
+) -> Pin<Box<dyn Stream<Item = Result<Vec<u8>, String>> + Send>> {
+    Box::pin(futures::stream::unfold(Some(chunks), |state| async move {
+        let chunks = state?;
+
+        let next_awaitable = Python::with_gil(|py| {
+            chunks
+                .bind(py)
+                .call_method0("__anext__")
+                .map(|bound| bound.unbind())
+        });
+        let next_obj = match next_awaitable {
+            Ok(obj) => obj,
+            Err(e) => return Some((Err(e.to_string()), None)),
+        };
+
+        let future = match Python::with_gil(|py| {
+            pyo3_async_runtimes::tokio::into_future(next_obj.bind(py).clone())
+        }) {
+            Ok(f) => f,
+            Err(e) => return Some((Err(e.to_string()), None)),
+        };
+
+        match future.await {
+            Ok(item) => match Python::with_gil(|py| item.bind(py).extract::<Vec<u8>>()) {
+                Ok(bytes) => Some((Ok(bytes), Some(chunks))),
+                Err(e) => Some((Err(e.to_string()), None)),
+            },
+            Err(e) => {
+                let is_stop = Python::with_gil(|py| {
+                    e.is_instance_of::<pyo3::exceptions::PyStopAsyncIteration>(py)
+                });
+                if is_stop {
+                    None
+                } else {
+                    Some((Err(e.to_string()), None))
+                }
+            }
+        }
+    }))
+}
+
+/// Feed a Python async iterator of `bytes` into the backend incrementally,
+/// via `ArtifactService::save_stream`, instead of materializing the whole
+/// payload in memory first.
+async fn save_stream_generic<S: ArtifactService>(
+    inner: &S,
+    app_name: String,
+    user_id: String,
+    session_id: String,
+    file_name: String,
+    mime_type: String,
+    chunks: Py<PyAny>,
+    version: Option<i64>,
+) -> PyResult<i64> {
+    let request = SaveStreamRequest {
+        app_name,
+        user_id,
+        session_id,
+        file_name,
+        mime_type,
+        version,
+        chunks: python_byte_stream(chunks),
+    };
+
+    let response = inner
+        .save_stream(request)
+        .await
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+    Ok(response.version)
+}
+
+/// Async iterator of raw byte chunks, returned by `load_stream`.
+///
+/// Use with `async for`:
+/// ```python
+/// async for chunk in service.load_stream(app_name, user_id, session_id, file_name):
+///     out.write(chunk)
+/// ```
+#[pyclass(name = "ArtifactChunkStream")]
+pub struct PyArtifactChunkStream {
+    receiver: Arc<Mutex<tokio::sync::mpsc::Receiver<Result<Vec<u8>, String>>>>,
+}
+
+#[pymethods]
+impl PyArtifactChunkStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let receiver = self.receiver.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut rx = receiver.lock().await;
+            match rx.recv().await {
+                Some(Ok(chunk)) => {
+                    Python::with_gil(|py| Ok(Some(PyBytes::new_bound(py, &chunk).unbind())))
+                }
+                Some(Err(e)) => Err(pyo3::exceptions::PyRuntimeError::new_err(e)),
+                None => Err(pyo3::exceptions::PyStopAsyncIteration::new_err(())), // Stream exhausted - signals StopAsyncIteration
+            }
+        })
+    }
+}
+
+/// Open a backend `load_stream` and bridge its chunks to a Python-facing
+/// async iterator over a channel, so the backend can start producing
+/// chunks before the caller has consumed any of them.
+async fn load_stream_generic<S>(
+    inner: Arc<S>,
+    app_name: String,
+    user_id: String,
+    session_id: String,
+    file_name: String,
+    version: Option<i64>,
+) -> PyResult<PyArtifactChunkStream>
+where
+    S: ArtifactService + Send + Sync + 'static,
+{
+    let request = LoadStreamRequest {
+        app_name,
+        user_id,
+        session_id,
+        file_name,
+        version,
+    };
+
+    let mut backend_stream = inner
+        .load_stream(request)
+        .await
+        .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+        .chunks;
+
+    let (tx, rx) = tokio::sync::mpsc::channel(8);
+
+    crate::promise::spawn_gil_free(async move {
+        while let Some(item) = backend_stream.next().await {
+            let send_result = tx.send(item.map_err(|e| e.to_string())).await;
+            if send_result.is_err() {
+                break;
+            }
+        }
+    });
+
+    Ok(PyArtifactChunkStream {
+        receiver: Arc::new(Mutex::new(rx)),
+    })
+}
+
+/// Read-only view over loaded artifact bytes, avoiding the copy into a
+/// Python `bytes` object that `load()`'s `Part` would otherwise require.
+///
+/// Exposes Python's buffer protocol directly over the `Arc<Vec<u8>>`
+/// already produced by the load, so `memoryview(view)`,
+/// `hashlib.sha256(view)`, `socket.send(view)`, and `numpy.frombuffer(view)`
+/// all read those bytes in place rather than each making their own copy.
+/// This doesn't avoid the copy `load_decoded` makes out of the backend
+/// itself - every `ArtifactService` here returns an owned `Vec<u8>` from
+/// `load()` - only the further copy on the way into Python.
+#[pyclass(name = "ArtifactView")]
+pub struct PyArtifactView {
+    data: Arc<Vec<u8>>,
+}
+
+#[pymethods]
+impl PyArtifactView {
+    fn __len__(&self) -> usize {
+        self.data.len()
+    }
+
+    unsafe fn __getbuffer__(
+        slf: Bound<'_, Self>,
+        view: *mut pyo3::ffi::Py_buffer,
+        flags: c_int,
+    ) -> PyResult<()> {
+        let ptr = slf.as_ptr();
+        let borrowed = slf.borrow();
+        let result = pyo3::ffi::PyBuffer_FillInfo(
+            view,
+            ptr,
+            borrowed.data.as_ptr() as *mut c_void,
+            borrowed.data.len() as isize,
+            1, // read-only
+            flags,
+        );
+        if result == -1 {
+            return Err(PyErr::fetch(slf.py()));
+        }
+        Ok(())
+    }
+
+    unsafe fn __releasebuffer__(&self, _view: *mut pyo3::ffi::Py_buffer) {}
+
+    fn __repr__(&self) -> String {
+        format!("ArtifactView({} bytes)", self.data.len())
+    }
+}
+
+/// In-memory artifact service for binary data storage
+#[pyclass(name = "InMemoryArtifactService")]
+pub struct PyInMemoryArtifactService {
+    inner: Arc<InMemoryArtifactService>,
+}
+
+#[pymethods]
+impl PyInMemoryArtifactService {
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: Arc::new(InMemoryArtifactService::new()),
+        }
+    }
+
+    /// Save an artifact (bytes or text)
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name (prefix with "user:" for user-scoped)
+    ///     data: Binary data (bytes) or text (str)
+    ///     mime_type: Optional MIME type (defaults to application/octet-stream for bytes)
+    ///     version: Optional version number (auto-increments if not specified)
+    ///     delta: If true and data is text, store this version as a change
+    ///         set against its predecessor instead of a full copy (see
+    ///         `load_history`). No-op for non-text data.
+    ///
+    /// Returns:
+    ///     Version number of saved artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, data, mime_type=None, version=None, delta=false))]
+    fn save<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        data: Bound<'py, PyAny>,
+        mime_type: Option<String>,
+        version: Option<i64>,
+        delta: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        if delta {
+            if let Ok(text) = data.extract::<String>() {
+                return pyo3_async_runtimes::tokio::future_into_py(py, async move {
+                    save_text_delta(
+                        inner.as_ref(),
+                        app_name,
+                        user_id,
+                        session_id,
+                        file_name,
+                        text,
+                        version,
+                    )
+                    .await
+                });
+            }
+        }
+
+        let part = part_from_data(&data, mime_type)?;
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = SaveRequest {
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                part,
+                version,
+            };
+
+            let response = inner
+                .save(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(response.version)
+        })
+    }
+
+    /// Load an artifact
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///     version: Optional version (loads latest if not specified)
+    ///
+    /// Returns:
+    ///     Part containing the artifact data
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let part = load_decoded(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            )
+            .await?;
+
+            Ok(PyPart::from(part))
+        })
+    }
+
+    /// Load an artifact as a buffer-protocol `ArtifactView` instead of a
+    /// `Part` whose bytes get copied into a Python `bytes` object. Loading
+    /// still goes through the same `load_decoded` path as `load()` - this
+    /// only saves the final copy on the way into Python, not a read from
+    /// the backend itself (see `ArtifactView`).
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///     version: Optional version (loads latest if not specified)
+    ///
+    /// Returns:
+    ///     ArtifactView: a read-only, buffer-protocol-compatible view over
+    ///     the artifact bytes
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load_view<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let part = load_decoded(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            )
+            .await?;
+
+            let bytes = match part {
+                Part::InlineData { data, .. } => data,
+                Part::Text { text } => text.into_bytes(),
+                _ => {
+                    return Err(pyo3::exceptions::PyTypeError::new_err(
+                        "artifact is not viewable as bytes",
+                    ))
+                }
+            };
+
+            Ok(PyArtifactView {
+                data: Arc::new(bytes),
+            })
+        })
+    }
+
+    /// Load the change history of a delta-mode text artifact.
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///
+    /// Returns:
+    ///     List[TextChange]: The recorded changes, in ascending version
+    ///     order. Versions saved as a full snapshot contribute no entries.
+    #[pyo3(signature = (app_name, user_id, session_id, file_name))]
+    fn load_history<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            history_text_delta(inner.as_ref(), app_name, user_id, session_id, file_name).await
+        })
+    }
+
+    /// Save an artifact incrementally from an async iterator of `bytes`,
+    /// without materializing the whole payload in memory.
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///     chunks: An async iterator/generator yielding `bytes`
+    ///     mime_type: Optional MIME type (defaults to application/octet-stream)
+    ///     version: Optional version number (auto-increments if not specified)
+    ///
+    /// Returns:
+    ///     Version number of the saved artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, chunks, mime_type=None, version=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn save_stream<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        chunks: Py<PyAny>,
+        mime_type: Option<String>,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+        let mime_type = mime_type.unwrap_or_else(|| "application/octet-stream".to_string());
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            save_stream_generic(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                mime_type,
+                chunks,
+                version,
+            )
+            .await
+        })
+    }
+
+    /// Load an artifact as an async iterator of `bytes` chunks, without
+    /// materializing the whole payload in memory.
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///     version: Optional version (loads latest if not specified)
+    ///
+    /// Returns:
+    ///     ArtifactChunkStream: async iterator yielding `bytes` chunks
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load_stream<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            load_stream_generic(inner, app_name, user_id, session_id, file_name, version).await
+        })
+    }
+
+    /// Save a PyArrow `RecordBatch`/`Table` as a versioned artifact,
+    /// encoded as an Arrow IPC stream (`ARROW_STREAM_MIME`), so analysts
+    /// can load it back into Arrow/pandas without a lossy bytes
+    /// round-trip.
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, table, version=None))]
+    fn save_table<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        table: &Bound<'py, PyAny>,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let batch = RecordBatch::from_pyarrow_bound(table)?;
+        let data = record_batch_to_ipc_bytes(&batch)?;
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            save_table_generic(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                data,
+                version,
+            )
+            .await
+        })
+    }
+
+    /// Load an Arrow IPC-stream artifact back as a PyArrow `RecordBatch`.
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load_table<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let batch = load_table_generic(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            )
+            .await?;
+
+            Python::with_gil(|py| batch.to_pyarrow(py))
+        })
+    }
+
+    /// Delete an artifact
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///     version: Optional version (deletes all versions if not specified)
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn delete<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = DeleteRequest {
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            };
+
+            inner
+                .delete(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(())
+        })
+    }
+
+    /// List all artifact names in a session
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///
+    /// Returns:
+    ///     List of artifact file names
+    #[pyo3(signature = (app_name, user_id, session_id))]
+    fn list<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = ListRequest {
+                app_name,
+                user_id,
+                session_id,
+            };
+
+            let response = inner
+                .list(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(response.file_names)
+        })
+    }
+
+    /// Get all versions of an artifact
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///
+    /// Returns:
+    ///     List of version numbers (descending order)
+    #[pyo3(signature = (app_name, user_id, session_id, file_name))]
+    fn versions<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = VersionsRequest {
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+            };
+
+            let response = inner
+                .versions(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(response.versions)
+        })
+    }
+}
+
+impl PyInMemoryArtifactService {
+    /// Get the inner Arc for use in Runner
+    pub fn inner(&self) -> Arc<InMemoryArtifactService> {
+        self.inner.clone()
+    }
+}
+
+/// Filesystem-backed artifact service - persists artifacts as files under a
+/// root directory, one file per `app_name/user_id/session_id/file_name/version`.
+#[pyclass(name = "FileSystemArtifactService")]
+pub struct PyFileSystemArtifactService {
+    inner: Arc<FileSystemArtifactService>,
+}
+
+#[pymethods]
+impl PyFileSystemArtifactService {
+    /// Open a filesystem artifact store rooted at `uri`.
+    ///
+    /// Args:
+    ///     uri: A `file://` URI or plain filesystem path. Created if it
+    ///         does not already exist.
+    #[new]
+    fn new(uri: String) -> PyResult<Self> {
+        let root = uri.strip_prefix("file://").unwrap_or(&uri);
+        let service = FileSystemArtifactService::new(root)
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(service),
+        })
+    }
+
+    /// Save an artifact (bytes or text)
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, data, mime_type=None, version=None, delta=false))]
+    fn save<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        data: Bound<'py, PyAny>,
+        mime_type: Option<String>,
+        version: Option<i64>,
+        delta: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        if delta {
+            if let Ok(text) = data.extract::<String>() {
+                return pyo3_async_runtimes::tokio::future_into_py(py, async move {
+                    save_text_delta(
+                        inner.as_ref(),
+                        app_name,
+                        user_id,
+                        session_id,
+                        file_name,
+                        text,
+                        version,
+                    )
+                    .await
+                });
+            }
+        }
+
+        let part = part_from_data(&data, mime_type)?;
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = SaveRequest {
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                part,
+                version,
+            };
+
+            let response = inner
+                .save(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(response.version)
+        })
+    }
+
+    /// Load an artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let part = load_decoded(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            )
+            .await?;
+
+            Ok(PyPart::from(part))
+        })
+    }
+
+    /// Load the change history of a delta-mode text artifact.
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///
+    /// Returns:
+    ///     List[TextChange]: The recorded changes, in ascending version
+    ///     order. Versions saved as a full snapshot contribute no entries.
+    #[pyo3(signature = (app_name, user_id, session_id, file_name))]
+    fn load_history<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            history_text_delta(inner.as_ref(), app_name, user_id, session_id, file_name).await
+        })
+    }
+
+    /// Save an artifact incrementally from an async iterator of `bytes`,
+    /// without materializing the whole payload in memory.
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///     chunks: An async iterator/generator yielding `bytes`
+    ///     mime_type: Optional MIME type (defaults to application/octet-stream)
+    ///     version: Optional version number (auto-increments if not specified)
+    ///
+    /// Returns:
+    ///     Version number of the saved artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, chunks, mime_type=None, version=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn save_stream<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        chunks: Py<PyAny>,
+        mime_type: Option<String>,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+        let mime_type = mime_type.unwrap_or_else(|| "application/octet-stream".to_string());
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            save_stream_generic(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                mime_type,
+                chunks,
+                version,
+            )
+            .await
+        })
+    }
+
+    /// Load an artifact as an async iterator of `bytes` chunks, without
+    /// materializing the whole payload in memory.
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///     version: Optional version (loads latest if not specified)
+    ///
+    /// Returns:
+    ///     ArtifactChunkStream: async iterator yielding `bytes` chunks
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load_stream<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            load_stream_generic(inner, app_name, user_id, session_id, file_name, version).await
+        })
+    }
+
+    /// Save a PyArrow `RecordBatch`/`Table` as a versioned artifact,
+    /// encoded as an Arrow IPC stream (`ARROW_STREAM_MIME`), so analysts
+    /// can load it back into Arrow/pandas without a lossy bytes
+    /// round-trip.
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, table, version=None))]
+    fn save_table<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        table: &Bound<'py, PyAny>,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let batch = RecordBatch::from_pyarrow_bound(table)?;
+        let data = record_batch_to_ipc_bytes(&batch)?;
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            save_table_generic(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                data,
+                version,
+            )
+            .await
+        })
+    }
+
+    /// Load an Arrow IPC-stream artifact back as a PyArrow `RecordBatch`.
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load_table<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let batch = load_table_generic(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            )
+            .await?;
+
+            Python::with_gil(|py| batch.to_pyarrow(py))
+        })
+    }
+
+    /// Delete an artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn delete<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = DeleteRequest {
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            };
+
+            inner
+                .delete(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(())
+        })
+    }
+
+    /// List all artifact names in a session
+    #[pyo3(signature = (app_name, user_id, session_id))]
+    fn list<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = ListRequest {
+                app_name,
+                user_id,
+                session_id,
+            };
+
+            let response = inner
+                .list(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(response.file_names)
+        })
+    }
+
+    /// Get all versions of an artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name))]
+    fn versions<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = VersionsRequest {
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+            };
+
+            let response = inner
+                .versions(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(response.versions)
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        "FileSystemArtifactService()".to_string()
+    }
+}
+
+/// S3-backed artifact service - persists artifacts as objects in an S3
+/// bucket, keyed by `app_name/user_id/session_id/file_name/version`.
+#[pyclass(name = "S3ArtifactService")]
+pub struct PyS3ArtifactService {
+    inner: Arc<S3ArtifactService>,
+}
+
+#[pymethods]
+impl PyS3ArtifactService {
+    /// Connect to an S3 bucket for artifact storage.
+    ///
+    /// Args:
+    ///     uri: An `s3://bucket[/prefix]` URI. Credentials and region are
+    ///         resolved from the environment, same as the AWS CLI/SDK.
+    #[staticmethod]
+    fn connect(py: Python<'_>, uri: String) -> PyResult<Bound<'_, PyAny>> {
+        let (bucket, prefix) = parse_bucket_uri(&uri, "s3://")?;
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let service = S3ArtifactService::connect(&bucket, prefix.as_deref())
+                .await
+                .map_err(|e| crate::error::ConnectionError::new_err(e.to_string()))?;
+            Ok(Self {
+                inner: Arc::new(service),
+            })
+        })
+    }
+
+    /// Save an artifact (bytes or text)
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, data, mime_type=None, version=None, delta=false))]
+    fn save<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        data: Bound<'py, PyAny>,
+        mime_type: Option<String>,
+        version: Option<i64>,
+        delta: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        if delta {
+            if let Ok(text) = data.extract::<String>() {
+                return pyo3_async_runtimes::tokio::future_into_py(py, async move {
+                    save_text_delta(
+                        inner.as_ref(),
+                        app_name,
+                        user_id,
+                        session_id,
+                        file_name,
+                        text,
+                        version,
+                    )
+                    .await
+                });
+            }
+        }
+
+        let part = part_from_data(&data, mime_type)?;
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = SaveRequest {
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                part,
+                version,
+            };
+
+            let response = inner
+                .save(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(response.version)
+        })
+    }
+
+    /// Load an artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let part = load_decoded(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            )
+            .await?;
+
+            Ok(PyPart::from(part))
+        })
+    }
+
+    /// Load the change history of a delta-mode text artifact.
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///
+    /// Returns:
+    ///     List[TextChange]: The recorded changes, in ascending version
+    ///     order. Versions saved as a full snapshot contribute no entries.
+    #[pyo3(signature = (app_name, user_id, session_id, file_name))]
+    fn load_history<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            history_text_delta(inner.as_ref(), app_name, user_id, session_id, file_name).await
+        })
+    }
+
+    /// Save an artifact incrementally from an async iterator of `bytes`,
+    /// without materializing the whole payload in memory.
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///     chunks: An async iterator/generator yielding `bytes`
+    ///     mime_type: Optional MIME type (defaults to application/octet-stream)
+    ///     version: Optional version number (auto-increments if not specified)
+    ///
+    /// Returns:
+    ///     Version number of the saved artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, chunks, mime_type=None, version=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn save_stream<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        chunks: Py<PyAny>,
+        mime_type: Option<String>,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+        let mime_type = mime_type.unwrap_or_else(|| "application/octet-stream".to_string());
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            save_stream_generic(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                mime_type,
+                chunks,
+                version,
+            )
+            .await
+        })
+    }
+
+    /// Load an artifact as an async iterator of `bytes` chunks, without
+    /// materializing the whole payload in memory.
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///     version: Optional version (loads latest if not specified)
+    ///
+    /// Returns:
+    ///     ArtifactChunkStream: async iterator yielding `bytes` chunks
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load_stream<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            load_stream_generic(inner, app_name, user_id, session_id, file_name, version).await
+        })
+    }
+
+    /// Save a PyArrow `RecordBatch`/`Table` as a versioned artifact,
+    /// encoded as an Arrow IPC stream (`ARROW_STREAM_MIME`), so analysts
+    /// can load it back into Arrow/pandas without a lossy bytes
+    /// round-trip.
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, table, version=None))]
+    fn save_table<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        table: &Bound<'py, PyAny>,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let batch = RecordBatch::from_pyarrow_bound(table)?;
+        let data = record_batch_to_ipc_bytes(&batch)?;
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            save_table_generic(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                data,
+                version,
+            )
+            .await
+        })
+    }
+
+    /// Load an Arrow IPC-stream artifact back as a PyArrow `RecordBatch`.
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load_table<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let batch = load_table_generic(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            )
+            .await?;
+
+            Python::with_gil(|py| batch.to_pyarrow(py))
+        })
+    }
+
+    /// Delete an artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn delete<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = DeleteRequest {
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            };
+
+            inner
+                .delete(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(())
+        })
+    }
+
+    /// List all artifact names in a session
+    #[pyo3(signature = (app_name, user_id, session_id))]
+    fn list<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = ListRequest {
+                app_name,
+                user_id,
+                session_id,
+            };
+
+            let response = inner
+                .list(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(response.file_names)
+        })
+    }
+
+    /// Get all versions of an artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name))]
+    fn versions<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = VersionsRequest {
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+            };
+
+            let response = inner
+                .versions(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(response.versions)
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        "S3ArtifactService()".to_string()
+    }
+}
+
+/// GCS-backed artifact service - persists artifacts as objects in a Google
+/// Cloud Storage bucket, keyed by `app_name/user_id/session_id/file_name/version`.
+#[pyclass(name = "GcsArtifactService")]
+pub struct PyGcsArtifactService {
+    inner: Arc<GcsArtifactService>,
+}
+
+#[pymethods]
+impl PyGcsArtifactService {
+    /// Connect to a GCS bucket for artifact storage.
+    ///
+    /// Args:
+    ///     uri: A `gs://bucket[/prefix]` URI. Credentials are resolved
+    ///         from the environment (application default credentials).
+    #[staticmethod]
+    fn connect(py: Python<'_>, uri: String) -> PyResult<Bound<'_, PyAny>> {
+        let (bucket, prefix) = parse_bucket_uri(&uri, "gs://")?;
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let service = GcsArtifactService::connect(&bucket, prefix.as_deref())
+                .await
+                .map_err(|e| crate::error::ConnectionError::new_err(e.to_string()))?;
+            Ok(Self {
+                inner: Arc::new(service),
+            })
+        })
+    }
+
+    /// Save an artifact (bytes or text)
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, data, mime_type=None, version=None, delta=false))]
+    fn save<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        data: Bound<'py, PyAny>,
+        mime_type: Option<String>,
+        version: Option<i64>,
+        delta: bool,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        if delta {
+            if let Ok(text) = data.extract::<String>() {
+                return pyo3_async_runtimes::tokio::future_into_py(py, async move {
+                    save_text_delta(
+                        inner.as_ref(),
+                        app_name,
+                        user_id,
+                        session_id,
+                        file_name,
+                        text,
+                        version,
+                    )
+                    .await
+                });
+            }
+        }
+
+        let part = part_from_data(&data, mime_type)?;
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = SaveRequest {
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                part,
+                version,
+            };
+
+            let response = inner
+                .save(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(response.version)
+        })
+    }
+
+    /// Load an artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let part = load_decoded(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            )
+            .await?;
+
+            Ok(PyPart::from(part))
+        })
+    }
+
+    /// Load the change history of a delta-mode text artifact.
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///
+    /// Returns:
+    ///     List[TextChange]: The recorded changes, in ascending version
+    ///     order. Versions saved as a full snapshot contribute no entries.
+    #[pyo3(signature = (app_name, user_id, session_id, file_name))]
+    fn load_history<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            history_text_delta(inner.as_ref(), app_name, user_id, session_id, file_name).await
+        })
+    }
+
+    /// Save an artifact incrementally from an async iterator of `bytes`,
+    /// without materializing the whole payload in memory.
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///     chunks: An async iterator/generator yielding `bytes`
+    ///     mime_type: Optional MIME type (defaults to application/octet-stream)
+    ///     version: Optional version number (auto-increments if not specified)
+    ///
+    /// Returns:
+    ///     Version number of the saved artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, chunks, mime_type=None, version=None))]
+    #[allow(clippy::too_many_arguments)]
+    fn save_stream<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        chunks: Py<PyAny>,
+        mime_type: Option<String>,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+        let mime_type = mime_type.unwrap_or_else(|| "application/octet-stream".to_string());
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            save_stream_generic(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                mime_type,
+                chunks,
+                version,
+            )
+            .await
+        })
+    }
+
+    /// Load an artifact as an async iterator of `bytes` chunks, without
+    /// materializing the whole payload in memory.
+    ///
+    /// Args:
+    ///     app_name: Application name
+    ///     user_id: User ID
+    ///     session_id: Session ID
+    ///     file_name: Artifact name
+    ///     version: Optional version (loads latest if not specified)
+    ///
+    /// Returns:
+    ///     ArtifactChunkStream: async iterator yielding `bytes` chunks
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load_stream<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            load_stream_generic(inner, app_name, user_id, session_id, file_name, version).await
+        })
+    }
+
+    /// Save a PyArrow `RecordBatch`/`Table` as a versioned artifact,
+    /// encoded as an Arrow IPC stream (`ARROW_STREAM_MIME`), so analysts
+    /// can load it back into Arrow/pandas without a lossy bytes
+    /// round-trip.
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, table, version=None))]
+    fn save_table<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        table: &Bound<'py, PyAny>,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let batch = RecordBatch::from_pyarrow_bound(table)?;
+        let data = record_batch_to_ipc_bytes(&batch)?;
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            save_table_generic(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                data,
+                version,
+            )
+            .await
+        })
+    }
+
+    /// Load an Arrow IPC-stream artifact back as a PyArrow `RecordBatch`.
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn load_table<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let batch = load_table_generic(
+                inner.as_ref(),
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            )
+            .await?;
+
+            Python::with_gil(|py| batch.to_pyarrow(py))
+        })
+    }
+
+    /// Delete an artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name, version=None))]
+    fn delete<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+        version: Option<i64>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = DeleteRequest {
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+                version,
+            };
+
+            inner
+                .delete(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(())
+        })
+    }
+
+    /// List all artifact names in a session
+    #[pyo3(signature = (app_name, user_id, session_id))]
+    fn list<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = ListRequest {
+                app_name,
+                user_id,
+                session_id,
+            };
+
+            let response = inner
+                .list(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(response.file_names)
+        })
+    }
+
+    /// Get all versions of an artifact
+    #[pyo3(signature = (app_name, user_id, session_id, file_name))]
+    fn versions<'py>(
+        &self,
+        py: Python<'py>,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        file_name: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let inner = self.inner.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let request = VersionsRequest {
+                app_name,
+                user_id,
+                session_id,
+                file_name,
+            };
+
+            let response = inner
+                .versions(request)
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+
+            Ok(response.versions)
+        })
+    }
+
+    fn __repr__(&self) -> String {
+        "GcsArtifactService()".to_string()
+    }
+}
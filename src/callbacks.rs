@@ -1,461 +1,799 @@
-//! Callback bindings for Python
-//!
-//! This module provides Python callback support for agent, model, and tool lifecycle hooks.
-//! Callbacks can intercept and modify behavior at various stages of agent execution.
-
-use adk_core::{
-    AfterAgentCallback, AfterModelCallback, AfterToolCallback, BeforeAgentCallback,
-    BeforeModelCallback, BeforeModelResult, BeforeToolCallback, CallbackContext, Content,
-    LlmRequest, LlmResponse,
-};
-use pyo3::prelude::*;
-use std::future::Future;
-use std::pin::Pin;
-use std::sync::Arc;
-
-use crate::context::PyCallbackContext;
-use crate::types::PyContent;
-
-// ============================================================================
-// Python callback wrapper types
-// ============================================================================
-
-/// Wrapper to hold a Python callback function safely across threads
-pub struct PythonCallback {
-    callback: Py<PyAny>,
-}
-
-unsafe impl Send for PythonCallback {}
-unsafe impl Sync for PythonCallback {}
-
-impl Clone for PythonCallback {
-    fn clone(&self) -> Self {
-        Python::with_gil(|py| Self {
-            callback: self.callback.clone_ref(py),
-        })
-    }
-}
-
-impl PythonCallback {
-    pub fn new(callback: Py<PyAny>) -> Self {
-        Self { callback }
-    }
-
-    /// Call the Python callback and return an optional Content
-    fn call_for_content(&self, ctx: Arc<dyn CallbackContext>) -> Option<Content> {
-        Python::with_gil(|py| {
-            let py_ctx = PyCallbackContext::from_callback_context(ctx.as_ref());
-            match self.callback.call1(py, (py_ctx,)) {
-                Ok(result) => {
-                    // Check if result is awaitable (coroutine)
-                    let asyncio = py.import_bound("asyncio").ok()?;
-                    let is_coro = asyncio
-                        .call_method1("iscoroutine", (&result,))
-                        .ok()?
-                        .is_truthy()
-                        .ok()?;
-
-                    let final_result = if is_coro {
-                        asyncio.call_method1("run", (&result,)).ok()?
-                    } else {
-                        result.into_bound(py)
-                    };
-
-                    // Return None if Python returned None
-                    if final_result.is_none() {
-                        return None;
-                    }
-
-                    // Try to extract as PyContent
-                    if let Ok(content) = final_result.extract::<PyContent>() {
-                        return Some(content.into());
-                    }
-
-                    // Try to extract as string and convert to Content
-                    if let Ok(text) = final_result.extract::<String>() {
-                        return Some(Content::new("model").with_text(&text));
-                    }
-
-                    None
-                }
-                Err(_) => None,
-            }
-        })
-    }
-
-    /// Call the Python before_model callback and return BeforeModelResult
-    fn call_for_before_model(
-        &self,
-        ctx: Arc<dyn CallbackContext>,
-        request: LlmRequest,
-    ) -> BeforeModelResult {
-        Python::with_gil(|py| {
-            let py_ctx = PyCallbackContext::from_callback_context(ctx.as_ref());
-            let py_request = PyLlmRequest::from(request.clone());
-
-            match self.callback.call1(py, (py_ctx, py_request)) {
-                Ok(result) => {
-                    // Check if result is awaitable (coroutine)
-                    let asyncio = match py.import_bound("asyncio") {
-                        Ok(a) => a,
-                        Err(_) => return BeforeModelResult::Continue(request),
-                    };
-
-                    let is_coro = match asyncio.call_method1("iscoroutine", (&result,)) {
-                        Ok(r) => r.is_truthy().unwrap_or(false),
-                        Err(_) => false,
-                    };
-
-                    let final_result = if is_coro {
-                        match asyncio.call_method1("run", (&result,)) {
-                            Ok(r) => r,
-                            Err(_) => return BeforeModelResult::Continue(request),
-                        }
-                    } else {
-                        result.into_bound(py)
-                    };
-
-                    // Return None/Continue if Python returned None
-                    if final_result.is_none() {
-                        return BeforeModelResult::Continue(request);
-                    }
-
-                    // Check if it's a BeforeModelResult
-                    if let Ok(bmr) = final_result.extract::<PyBeforeModelResult>() {
-                        return bmr.into_rust(request);
-                    }
-
-                    // If string returned, treat as skip with that response
-                    if let Ok(text) = final_result.extract::<String>() {
-                        let response = LlmResponse::new(Content::new("model").with_text(&text));
-                        return BeforeModelResult::Skip(response);
-                    }
-
-                    BeforeModelResult::Continue(request)
-                }
-                Err(_) => BeforeModelResult::Continue(request),
-            }
-        })
-    }
-
-    /// Call the Python after_model callback and return optional modified LlmResponse
-    fn call_for_after_model(
-        &self,
-        ctx: Arc<dyn CallbackContext>,
-        response: LlmResponse,
-    ) -> Option<LlmResponse> {
-        Python::with_gil(|py| {
-            let py_ctx = PyCallbackContext::from_callback_context(ctx.as_ref());
-            let py_response = PyLlmResponse::from(response.clone());
-
-            match self.callback.call1(py, (py_ctx, py_response)) {
-                Ok(result) => {
-                    // Check if result is awaitable (coroutine)
-                    let asyncio = py.import_bound("asyncio").ok()?;
-                    let is_coro = asyncio
-                        .call_method1("iscoroutine", (&result,))
-                        .ok()?
-                        .is_truthy()
-                        .ok()?;
-
-                    let final_result = if is_coro {
-                        asyncio.call_method1("run", (&result,)).ok()?
-                    } else {
-                        result.into_bound(py)
-                    };
-
-                    // Return None if Python returned None (no modification)
-                    if final_result.is_none() {
-                        return None;
-                    }
-
-                    // Try to extract modified response
-                    if let Ok(py_resp) = final_result.extract::<PyLlmResponse>() {
-                        return Some(py_resp.into());
-                    }
-
-                    None
-                }
-                Err(_) => None,
-            }
-        })
-    }
-}
-
-// ============================================================================
-// Factory functions to create Rust callbacks from Python functions
-// ============================================================================
-
-/// Create a BeforeAgentCallback from a Python function
-pub fn create_before_agent_callback(py_callback: Py<PyAny>) -> BeforeAgentCallback {
-    let wrapper = PythonCallback::new(py_callback);
-    Box::new(
-        move |ctx: Arc<dyn CallbackContext>| -> Pin<
-            Box<dyn Future<Output = adk_core::Result<Option<Content>>> + Send>,
-        > {
-            let wrapper = wrapper.clone();
-            Box::pin(async move {
-                let result = tokio::task::spawn_blocking(move || wrapper.call_for_content(ctx))
-                    .await
-                    .map_err(|e| {
-                        adk_core::AdkError::Agent(format!("Before agent callback failed: {}", e))
-                    })?;
-                Ok(result)
-            })
-        },
-    )
-}
-
-/// Create an AfterAgentCallback from a Python function
-pub fn create_after_agent_callback(py_callback: Py<PyAny>) -> AfterAgentCallback {
-    let wrapper = PythonCallback::new(py_callback);
-    Box::new(
-        move |ctx: Arc<dyn CallbackContext>| -> Pin<
-            Box<dyn Future<Output = adk_core::Result<Option<Content>>> + Send>,
-        > {
-            let wrapper = wrapper.clone();
-            Box::pin(async move {
-                let result = tokio::task::spawn_blocking(move || wrapper.call_for_content(ctx))
-                    .await
-                    .map_err(|e| {
-                        adk_core::AdkError::Agent(format!("After agent callback failed: {}", e))
-                    })?;
-                Ok(result)
-            })
-        },
-    )
-}
-
-/// Create a BeforeModelCallback from a Python function
-pub fn create_before_model_callback(py_callback: Py<PyAny>) -> BeforeModelCallback {
-    let wrapper = PythonCallback::new(py_callback);
-    Box::new(
-        move |ctx: Arc<dyn CallbackContext>,
-              request: LlmRequest|
-              -> Pin<Box<dyn Future<Output = adk_core::Result<BeforeModelResult>> + Send>> {
-            let wrapper = wrapper.clone();
-            Box::pin(async move {
-                let result = tokio::task::spawn_blocking(move || {
-                    wrapper.call_for_before_model(ctx, request)
-                })
-                .await
-                .map_err(|e| {
-                    adk_core::AdkError::Agent(format!("Before model callback failed: {}", e))
-                })?;
-                Ok(result)
-            })
-        },
-    )
-}
-
-/// Create an AfterModelCallback from a Python function
-pub fn create_after_model_callback(py_callback: Py<PyAny>) -> AfterModelCallback {
-    let wrapper = PythonCallback::new(py_callback);
-    Box::new(
-        move |ctx: Arc<dyn CallbackContext>, response: LlmResponse| -> Pin<
-            Box<dyn Future<Output = adk_core::Result<Option<LlmResponse>>> + Send>,
-        > {
-            let wrapper = wrapper.clone();
-            Box::pin(async move {
-                let result = tokio::task::spawn_blocking(move || {
-                    wrapper.call_for_after_model(ctx, response)
-                })
-                .await
-                .map_err(|e| {
-                    adk_core::AdkError::Agent(format!("After model callback failed: {}", e))
-                })?;
-                Ok(result)
-            })
-        },
-    )
-}
-
-/// Create a BeforeToolCallback from a Python function
-pub fn create_before_tool_callback(py_callback: Py<PyAny>) -> BeforeToolCallback {
-    let wrapper = PythonCallback::new(py_callback);
-    Box::new(
-        move |ctx: Arc<dyn CallbackContext>| -> Pin<
-            Box<dyn Future<Output = adk_core::Result<Option<Content>>> + Send>,
-        > {
-            let wrapper = wrapper.clone();
-            Box::pin(async move {
-                let result = tokio::task::spawn_blocking(move || wrapper.call_for_content(ctx))
-                    .await
-                    .map_err(|e| {
-                        adk_core::AdkError::Agent(format!("Before tool callback failed: {}", e))
-                    })?;
-                Ok(result)
-            })
-        },
-    )
-}
-
-/// Create an AfterToolCallback from a Python function
-pub fn create_after_tool_callback(py_callback: Py<PyAny>) -> AfterToolCallback {
-    let wrapper = PythonCallback::new(py_callback);
-    Box::new(
-        move |ctx: Arc<dyn CallbackContext>| -> Pin<
-            Box<dyn Future<Output = adk_core::Result<Option<Content>>> + Send>,
-        > {
-            let wrapper = wrapper.clone();
-            Box::pin(async move {
-                let result = tokio::task::spawn_blocking(move || wrapper.call_for_content(ctx))
-                    .await
-                    .map_err(|e| {
-                        adk_core::AdkError::Agent(format!("After tool callback failed: {}", e))
-                    })?;
-                Ok(result)
-            })
-        },
-    )
-}
-
-// ============================================================================
-// Python-exposed types for callbacks
-// ============================================================================
-
-/// Python wrapper for LlmRequest
-#[pyclass(name = "LlmRequest")]
-#[derive(Clone)]
-pub struct PyLlmRequest {
-    #[pyo3(get)]
-    pub model: String,
-    #[pyo3(get)]
-    pub contents: Vec<PyContent>,
-}
-
-impl From<LlmRequest> for PyLlmRequest {
-    fn from(req: LlmRequest) -> Self {
-        Self {
-            model: req.model,
-            contents: req.contents.into_iter().map(PyContent::from).collect(),
-        }
-    }
-}
-
-#[pymethods]
-impl PyLlmRequest {
-    fn __repr__(&self) -> String {
-        format!(
-            "LlmRequest(model='{}', contents_count={})",
-            self.model,
-            self.contents.len()
-        )
-    }
-}
-
-/// Python wrapper for LlmResponse
-#[pyclass(name = "LlmResponse")]
-#[derive(Clone)]
-pub struct PyLlmResponse {
-    content: Option<PyContent>,
-    #[pyo3(get)]
-    pub partial: bool,
-    #[pyo3(get)]
-    pub turn_complete: bool,
-}
-
-impl From<LlmResponse> for PyLlmResponse {
-    fn from(resp: LlmResponse) -> Self {
-        Self {
-            content: resp.content.map(PyContent::from),
-            partial: resp.partial,
-            turn_complete: resp.turn_complete,
-        }
-    }
-}
-
-impl From<PyLlmResponse> for LlmResponse {
-    fn from(resp: PyLlmResponse) -> Self {
-        LlmResponse {
-            content: resp.content.map(|c| c.into()),
-            partial: resp.partial,
-            turn_complete: resp.turn_complete,
-            ..Default::default()
-        }
-    }
-}
-
-#[pymethods]
-impl PyLlmResponse {
-    #[new]
-    #[pyo3(signature = (content=None, partial=false, turn_complete=true))]
-    fn new(content: Option<PyContent>, partial: bool, turn_complete: bool) -> Self {
-        Self {
-            content,
-            partial,
-            turn_complete,
-        }
-    }
-
-    #[getter]
-    fn content(&self) -> Option<PyContent> {
-        self.content.clone()
-    }
-
-    fn get_text(&self) -> Option<String> {
-        self.content.as_ref().map(|c| c.extract_text())
-    }
-
-    fn __repr__(&self) -> String {
-        format!(
-            "LlmResponse(partial={}, turn_complete={})",
-            self.partial, self.turn_complete
-        )
-    }
-}
-
-/// Python wrapper for BeforeModelResult
-#[pyclass(name = "BeforeModelResult")]
-#[derive(Clone)]
-pub struct PyBeforeModelResult {
-    skip: bool,
-    response_text: Option<String>,
-}
-
-impl PyBeforeModelResult {
-    fn into_rust(self, request: LlmRequest) -> BeforeModelResult {
-        if self.skip {
-            let response = if let Some(text) = self.response_text {
-                LlmResponse::new(Content::new("model").with_text(&text))
-            } else {
-                LlmResponse::new(Content::new("model").with_text(""))
-            };
-            BeforeModelResult::Skip(response)
-        } else {
-            BeforeModelResult::Continue(request)
-        }
-    }
-}
-
-#[pymethods]
-impl PyBeforeModelResult {
-    /// Continue with the model call (possibly with modified request)
-    #[staticmethod]
-    fn cont() -> Self {
-        Self {
-            skip: false,
-            response_text: None,
-        }
-    }
-
-    /// Skip the model call and return the given response text
-    #[staticmethod]
-    fn skip(response_text: String) -> Self {
-        Self {
-            skip: true,
-            response_text: Some(response_text),
-        }
-    }
-
-    fn __repr__(&self) -> String {
-        if self.skip {
-            format!(
-                "BeforeModelResult.skip('{}')",
-                self.response_text.as_deref().unwrap_or("")
-            )
-        } else {
-            "BeforeModelResult.cont()".to_string()
-        }
-    }
-}
+//! Callback bindings for Python
+//!
+//! This module provides Python callback support for agent, model, and tool lifecycle hooks.
+//! Callbacks can intercept and modify behavior at various stages of agent execution.
+//!
+//! Callbacks may be plain callables or `async def` coroutines; `PythonCallback`
+//! classifies which once at registration (see `CallableKind`) rather than
+//! re-inspecting the callable on every call, and drives coroutines to
+//! completion on the event loop instead of handing back an un-awaited
+//! coroutine object.
+//!
+//! An `async def` callback's coroutine is driven via
+//! `pyo3_async_runtimes::tokio::into_future`, which schedules it on the
+//! runtime already registered for this interpreter rather than spinning up
+//! a fresh `asyncio` event loop per call - so calling back into a host
+//! app's own running loop never raises `asyncio.run() cannot be called
+//! from a running event loop`, and any loop-bound resources the host
+//! created (connection pools, background tasks) stay usable.
+
+use adk_core::{
+    AdkError, AfterAgentCallback, AfterModelCallback, AfterToolCallback, BeforeAgentCallback,
+    BeforeModelCallback, BeforeModelResult, BeforeToolCallback, CallbackContext, Content,
+    LlmRequest, LlmResponse, Result as AdkResult,
+};
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Arc;
+
+use crate::context::PyCallbackContext;
+use crate::session::PyGenerateContentConfig;
+use crate::types::{PyContent, PyEvent};
+
+// ============================================================================
+// Python callback wrapper types
+// ============================================================================
+
+/// How a callback should react when the wrapped Python callable raises.
+///
+/// Defaults to `Propagate`: a callback that silently swallows the
+/// exception and falls back to a no-op result hides genuine failures, so
+/// that's opt-in (`LogAndContinue`) rather than the default.
+#[pyclass(name = "CallbackErrorMode", eq, eq_int)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default)]
+pub enum PyCallbackErrorMode {
+    /// Turn the exception into an `AdkError::Agent` that stops the run.
+    #[default]
+    Propagate = 0,
+    /// Log the formatted exception via `tracing::warn!` and fall back to the
+    /// callback's no-op result (continue / keep the original value).
+    LogAndContinue = 1,
+}
+
+/// Format a PyErr's type, message, and traceback. `err.to_string()` alone
+/// already gives the type and message (pyo3's `Display` for `PyErr`), but
+/// drops the traceback, which is usually what's needed to find the bug.
+fn format_py_err(py: Python<'_>, err: &PyErr) -> String {
+    let traceback = err
+        .traceback(py)
+        .and_then(|tb| tb.format().ok())
+        .unwrap_or_default();
+    if traceback.is_empty() {
+        err.to_string()
+    } else {
+        format!("{err}\n{traceback}")
+    }
+}
+
+/// Whether a Python callback is an `async def` (or equivalent), determined
+/// once at registration so the hot path doesn't have to re-inspect the
+/// callable on every call.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum CallableKind {
+    Coroutine,
+    Sync,
+    /// Couldn't tell statically (e.g. a `__call__` object whose async-ness
+    /// isn't visible to `inspect.iscoroutinefunction`) - fall back to
+    /// inspecting each individual return value instead.
+    Unknown,
+}
+
+/// Inspect `callback` once to classify it, via `inspect.iscoroutinefunction`
+/// (which already unwraps `functools.partial` and bound methods). Plain
+/// callable objects expose their async-ness on `__call__` rather than on
+/// themselves, so that's checked too before giving up as `Unknown`.
+fn detect_callable_kind(py: Python<'_>, callback: &Py<PyAny>) -> CallableKind {
+    let Ok(inspect) = py.import("inspect") else {
+        return CallableKind::Unknown;
+    };
+    let is_coroutine_function = |obj: &Bound<'_, PyAny>| -> bool {
+        inspect
+            .call_method1("iscoroutinefunction", (obj,))
+            .and_then(|r| r.extract::<bool>())
+            .unwrap_or(false)
+    };
+
+    let bound = callback.bind(py);
+    if is_coroutine_function(bound) {
+        return CallableKind::Coroutine;
+    }
+    if bound.hasattr("__code__").unwrap_or(false) {
+        // A plain function or bound method that `iscoroutinefunction` already
+        // inspected fully above - confidently synchronous.
+        return CallableKind::Sync;
+    }
+    match bound.getattr("__call__") {
+        Ok(call) if is_coroutine_function(&call) => CallableKind::Coroutine,
+        Ok(_) => CallableKind::Unknown,
+        Err(_) => CallableKind::Sync,
+    }
+}
+
+/// Wrapper to hold a Python callback function safely across threads
+pub struct PythonCallback {
+    callback: Py<PyAny>,
+    error_mode: PyCallbackErrorMode,
+    kind: CallableKind,
+}
+
+unsafe impl Send for PythonCallback {}
+unsafe impl Sync for PythonCallback {}
+
+impl Clone for PythonCallback {
+    fn clone(&self) -> Self {
+        Python::with_gil(|py| Self {
+            callback: self.callback.clone_ref(py),
+            error_mode: self.error_mode,
+            kind: self.kind,
+        })
+    }
+}
+
+/// Extract a content-style callback's return value: `None` means no
+/// override, a `Content` is passed through, and a plain string is wrapped
+/// as model text - the same coercions for every content-returning callback
+/// kind (before/after agent, before/after tool).
+fn extract_content(value: &Bound<'_, PyAny>) -> Option<Content> {
+    if value.is_none() {
+        return None;
+    }
+    if let Ok(content) = value.extract::<PyContent>() {
+        return Some(content.into());
+    }
+    if let Ok(text) = value.extract::<String>() {
+        return Some(Content::new("model").with_text(&text));
+    }
+    None
+}
+
+/// Extract a before_model callback's return value against the request it
+/// was given: `None` continues with the original request, a
+/// `BeforeModelResult` is used as-is, and a plain string skips the model
+/// call with that text as the response.
+fn extract_before_model_result(value: &Bound<'_, PyAny>, request: LlmRequest) -> BeforeModelResult {
+    if value.is_none() {
+        return BeforeModelResult::Continue(request);
+    }
+    if let Ok(bmr) = value.extract::<PyBeforeModelResult>() {
+        return bmr.into_rust(request);
+    }
+    if let Ok(text) = value.extract::<String>() {
+        let response = LlmResponse::new(Content::new("model").with_text(&text));
+        return BeforeModelResult::Skip(response);
+    }
+    BeforeModelResult::Continue(request)
+}
+
+/// Extract an after_model callback's return value: `None` leaves the
+/// response unmodified, an `LlmResponse` replaces it.
+fn extract_after_model_result(value: &Bound<'_, PyAny>) -> Option<LlmResponse> {
+    if value.is_none() {
+        return None;
+    }
+    value.extract::<PyLlmResponse>().ok().map(Into::into)
+}
+
+/// What a single synchronous call into the Python callback produced: either
+/// its final result, already extracted, or - if the callback is an
+/// `async def` - the awaitable it returned, still to be driven to
+/// completion. Mirrors `tool::function::HandlerOutcome`.
+///
+/// `Awaitable` carries the `PyCallbackContext` handle alongside the
+/// coroutine: an `async def` callback's body (and therefore any
+/// `ctx.state` writes it makes) doesn't run until the coroutine is
+/// actually driven, which happens after this outcome is produced - so the
+/// state delta can only be drained once the coroutine has been awaited.
+enum CallOutcome<T> {
+    Done(T),
+    Awaitable(Py<PyAny>, Py<PyCallbackContext>),
+    Failed(PyErr),
+}
+
+/// Fold a callback's `ctx.state` writes into the invocation's own state
+/// delta, the same drain `agent/custom.rs` does for `CustomAgent`
+/// handlers. Unlike `CustomAgent`, these callbacks don't build their own
+/// `Event` - the result is handed back into `adk_core`'s own callback
+/// pipeline - so the delta goes through `ctx` itself rather than being
+/// attached to a return value.
+fn drain_state_delta(py: Python<'_>, ctx: &dyn CallbackContext, py_ctx: &Py<PyCallbackContext>) {
+    let delta = py_ctx.borrow(py).persistable_delta(py);
+    if !delta.is_empty() {
+        ctx.record_state_delta(delta);
+    }
+}
+
+/// `call_for_event`'s own outcome: there's no `PyCallbackContext` (and
+/// therefore no state delta) for a push-style event subscriber, so it
+/// doesn't need `CallOutcome`'s extra payload.
+enum EventCallOutcome {
+    Done,
+    Awaitable(Py<PyAny>),
+    Failed(PyErr),
+}
+
+impl PythonCallback {
+    pub fn new(callback: Py<PyAny>) -> Self {
+        Self::with_error_mode(callback, PyCallbackErrorMode::default())
+    }
+
+    pub fn with_error_mode(callback: Py<PyAny>, error_mode: PyCallbackErrorMode) -> Self {
+        let kind = Python::with_gil(|py| detect_callable_kind(py, &callback));
+        Self {
+            callback,
+            error_mode,
+            kind,
+        }
+    }
+
+    /// Whether a value returned from this callback is an un-awaited
+    /// coroutine. Uses the callable's cached `kind` when it was classified
+    /// confidently at registration; only falls back to inspecting this
+    /// particular return value when the cached kind was `Unknown`.
+    fn is_awaitable(&self, value: &Bound<'_, PyAny>) -> bool {
+        match self.kind {
+            CallableKind::Coroutine => true,
+            CallableKind::Sync => false,
+            CallableKind::Unknown => value.hasattr("__await__").unwrap_or(false),
+        }
+    }
+
+    /// Resolve a Python exception raised by this callback according to its
+    /// `error_mode`: propagate it as an `AdkError::Agent`, or log it and
+    /// fall back to the given no-op result.
+    fn resolve_failure<T>(&self, err: PyErr, fallback: T) -> AdkResult<T> {
+        let message = Python::with_gil(|py| format_py_err(py, &err));
+        match self.error_mode {
+            PyCallbackErrorMode::Propagate => Err(AdkError::Agent(message)),
+            PyCallbackErrorMode::LogAndContinue => {
+                tracing::warn!("callback raised, continuing: {message}");
+                Ok(fallback)
+            }
+        }
+    }
+
+    /// Call a content-style Python callback and return an optional
+    /// `Content`, driving an `async def` handler to completion on the
+    /// tokio runtime - awaiting it without holding the GIL, then
+    /// re-acquiring it to translate the result.
+    async fn call_for_content(&self, ctx: Arc<dyn CallbackContext>) -> AdkResult<Option<Content>> {
+        let wrapper = self.clone();
+        let ctx_for_call = ctx.clone();
+        let outcome = tokio::task::spawn_blocking(move || {
+            Python::with_gil(|py| {
+                // Keep our own handle to the context object (rather than
+                // letting `call1` consume a one-off conversion) so that
+                // after the callback returns we can read back whatever it
+                // wrote to `ctx.state` and drain it - see `drain_state_delta`.
+                let py_ctx = match Py::new(
+                    py,
+                    PyCallbackContext::from_callback_context(ctx_for_call.as_ref()),
+                ) {
+                    Ok(py_ctx) => py_ctx,
+                    Err(e) => return CallOutcome::Failed(e),
+                };
+                match wrapper.callback.call1(py, (py_ctx.clone_ref(py),)) {
+                    Ok(result) => {
+                        let bound = result.bind(py);
+                        // `async def` callbacks return an un-awaited coroutine
+                        // from `call1`; detect that instead of forcing every
+                        // callback to be synchronous.
+                        if wrapper.is_awaitable(bound) {
+                            CallOutcome::Awaitable(result, py_ctx)
+                        } else {
+                            drain_state_delta(py, ctx_for_call.as_ref(), &py_ctx);
+                            CallOutcome::Done(extract_content(bound))
+                        }
+                    }
+                    Err(e) => {
+                        drain_state_delta(py, ctx_for_call.as_ref(), &py_ctx);
+                        CallOutcome::Failed(e)
+                    }
+                }
+            })
+        })
+        .await
+        .unwrap_or(CallOutcome::Done(None));
+
+        match outcome {
+            CallOutcome::Done(content) => Ok(content),
+            CallOutcome::Failed(err) => self.resolve_failure(err, None),
+            CallOutcome::Awaitable(coro, py_ctx) => {
+                let future = match Python::with_gil(|py| {
+                    pyo3_async_runtimes::tokio::into_future(coro.bind(py).clone())
+                }) {
+                    Ok(future) => future,
+                    Err(e) => return self.resolve_failure(e, None),
+                };
+                match future.await {
+                    Ok(awaited) => Ok(Python::with_gil(|py| {
+                        drain_state_delta(py, ctx.as_ref(), &py_ctx);
+                        extract_content(awaited.bind(py))
+                    })),
+                    Err(e) => {
+                        Python::with_gil(|py| drain_state_delta(py, ctx.as_ref(), &py_ctx));
+                        self.resolve_failure(e, None)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Call the Python before_model callback and return a
+    /// `BeforeModelResult`, awaiting an `async def` handler the same way.
+    async fn call_for_before_model(
+        &self,
+        ctx: Arc<dyn CallbackContext>,
+        request: LlmRequest,
+    ) -> AdkResult<BeforeModelResult> {
+        let wrapper = self.clone();
+        let ctx_for_call = ctx.clone();
+        let request_for_call = request.clone();
+        let outcome = tokio::task::spawn_blocking(move || {
+            Python::with_gil(|py| {
+                let py_ctx = match Py::new(
+                    py,
+                    PyCallbackContext::from_callback_context(ctx_for_call.as_ref()),
+                ) {
+                    Ok(py_ctx) => py_ctx,
+                    Err(e) => return CallOutcome::Failed(e),
+                };
+                let py_request = PyLlmRequest::from(request_for_call.clone());
+                match wrapper
+                    .callback
+                    .call1(py, (py_ctx.clone_ref(py), py_request))
+                {
+                    Ok(result) => {
+                        let bound = result.bind(py);
+                        if wrapper.is_awaitable(bound) {
+                            CallOutcome::Awaitable(result, py_ctx)
+                        } else {
+                            drain_state_delta(py, ctx_for_call.as_ref(), &py_ctx);
+                            CallOutcome::Done(extract_before_model_result(
+                                bound,
+                                request_for_call.clone(),
+                            ))
+                        }
+                    }
+                    Err(e) => {
+                        drain_state_delta(py, ctx_for_call.as_ref(), &py_ctx);
+                        CallOutcome::Failed(e)
+                    }
+                }
+            })
+        })
+        .await
+        .unwrap_or_else(|_| CallOutcome::Done(BeforeModelResult::Continue(request.clone())));
+
+        match outcome {
+            CallOutcome::Done(result) => Ok(result),
+            CallOutcome::Failed(err) => {
+                self.resolve_failure(err, BeforeModelResult::Continue(request))
+            }
+            CallOutcome::Awaitable(coro, py_ctx) => {
+                let future = match Python::with_gil(|py| {
+                    pyo3_async_runtimes::tokio::into_future(coro.bind(py).clone())
+                }) {
+                    Ok(future) => future,
+                    Err(e) => return self.resolve_failure(e, BeforeModelResult::Continue(request)),
+                };
+                match future.await {
+                    Ok(awaited) => Ok(Python::with_gil(|py| {
+                        drain_state_delta(py, ctx.as_ref(), &py_ctx);
+                        extract_before_model_result(awaited.bind(py), request)
+                    })),
+                    Err(e) => {
+                        Python::with_gil(|py| drain_state_delta(py, ctx.as_ref(), &py_ctx));
+                        self.resolve_failure(e, BeforeModelResult::Continue(request))
+                    }
+                }
+            }
+        }
+    }
+
+    /// Call the Python after_model callback and return an optional
+    /// modified `LlmResponse`, awaiting an `async def` handler the same way.
+    async fn call_for_after_model(
+        &self,
+        ctx: Arc<dyn CallbackContext>,
+        response: LlmResponse,
+    ) -> AdkResult<Option<LlmResponse>> {
+        let wrapper = self.clone();
+        let ctx_for_call = ctx.clone();
+        let outcome = tokio::task::spawn_blocking(move || {
+            Python::with_gil(|py| {
+                let py_ctx = match Py::new(
+                    py,
+                    PyCallbackContext::from_callback_context(ctx_for_call.as_ref()),
+                ) {
+                    Ok(py_ctx) => py_ctx,
+                    Err(e) => return CallOutcome::Failed(e),
+                };
+                let py_response = PyLlmResponse::from(response);
+                match wrapper
+                    .callback
+                    .call1(py, (py_ctx.clone_ref(py), py_response))
+                {
+                    Ok(result) => {
+                        let bound = result.bind(py);
+                        if wrapper.is_awaitable(bound) {
+                            CallOutcome::Awaitable(result, py_ctx)
+                        } else {
+                            drain_state_delta(py, ctx_for_call.as_ref(), &py_ctx);
+                            CallOutcome::Done(extract_after_model_result(bound))
+                        }
+                    }
+                    Err(e) => {
+                        drain_state_delta(py, ctx_for_call.as_ref(), &py_ctx);
+                        CallOutcome::Failed(e)
+                    }
+                }
+            })
+        })
+        .await
+        .unwrap_or(CallOutcome::Done(None));
+
+        match outcome {
+            CallOutcome::Done(result) => Ok(result),
+            CallOutcome::Failed(err) => self.resolve_failure(err, None),
+            CallOutcome::Awaitable(coro, py_ctx) => {
+                let future = match Python::with_gil(|py| {
+                    pyo3_async_runtimes::tokio::into_future(coro.bind(py).clone())
+                }) {
+                    Ok(future) => future,
+                    Err(e) => return self.resolve_failure(e, None),
+                };
+                match future.await {
+                    Ok(awaited) => Ok(Python::with_gil(|py| {
+                        drain_state_delta(py, ctx.as_ref(), &py_ctx);
+                        extract_after_model_result(awaited.bind(py))
+                    })),
+                    Err(e) => {
+                        Python::with_gil(|py| drain_state_delta(py, ctx.as_ref(), &py_ctx));
+                        self.resolve_failure(e, None)
+                    }
+                }
+            }
+        }
+    }
+
+    /// Call a push-style callback (e.g. a `session.subscribe()` event
+    /// listener) with a single `Event` argument, awaiting an `async def`
+    /// handler the same way `call_for_content` does. There's no run for a
+    /// raised exception to stop here, so unlike the callbacks above this
+    /// doesn't consult `error_mode` - it's logged via `tracing::warn!` and
+    /// the next event is delivered regardless.
+    pub async fn call_for_event(&self, event: PyEvent) {
+        let wrapper = self.clone();
+        let outcome = tokio::task::spawn_blocking(move || {
+            Python::with_gil(|py| match wrapper.callback.call1(py, (event,)) {
+                Ok(result) => {
+                    let bound = result.bind(py);
+                    if wrapper.is_awaitable(bound) {
+                        EventCallOutcome::Awaitable(result)
+                    } else {
+                        EventCallOutcome::Done
+                    }
+                }
+                Err(e) => EventCallOutcome::Failed(e),
+            })
+        })
+        .await
+        .unwrap_or(EventCallOutcome::Done);
+
+        match outcome {
+            EventCallOutcome::Done => {}
+            EventCallOutcome::Failed(err) => self.log_event_callback_failure(err),
+            EventCallOutcome::Awaitable(coro) => {
+                let future = match Python::with_gil(|py| {
+                    pyo3_async_runtimes::tokio::into_future(coro.bind(py).clone())
+                }) {
+                    Ok(future) => future,
+                    Err(e) => return self.log_event_callback_failure(e),
+                };
+                if let Err(e) = future.await {
+                    self.log_event_callback_failure(e);
+                }
+            }
+        }
+    }
+
+    fn log_event_callback_failure(&self, err: PyErr) {
+        let message = Python::with_gil(|py| format_py_err(py, &err));
+        tracing::warn!("session event subscriber callback raised: {message}");
+    }
+}
+
+// ============================================================================
+// Factory functions to create Rust callbacks from Python functions
+// ============================================================================
+
+/// Create a BeforeAgentCallback from a Python function
+pub fn create_before_agent_callback(
+    py_callback: Py<PyAny>,
+    error_mode: PyCallbackErrorMode,
+) -> BeforeAgentCallback {
+    let wrapper = PythonCallback::with_error_mode(py_callback, error_mode);
+    Box::new(
+        move |ctx: Arc<dyn CallbackContext>| -> Pin<
+            Box<dyn Future<Output = adk_core::Result<Option<Content>>> + Send>,
+        > {
+            let wrapper = wrapper.clone();
+            Box::pin(async move { wrapper.call_for_content(ctx).await })
+        },
+    )
+}
+
+/// Create an AfterAgentCallback from a Python function
+pub fn create_after_agent_callback(
+    py_callback: Py<PyAny>,
+    error_mode: PyCallbackErrorMode,
+) -> AfterAgentCallback {
+    let wrapper = PythonCallback::with_error_mode(py_callback, error_mode);
+    Box::new(
+        move |ctx: Arc<dyn CallbackContext>| -> Pin<
+            Box<dyn Future<Output = adk_core::Result<Option<Content>>> + Send>,
+        > {
+            let wrapper = wrapper.clone();
+            Box::pin(async move { wrapper.call_for_content(ctx).await })
+        },
+    )
+}
+
+/// Create a BeforeModelCallback from a Python function
+pub fn create_before_model_callback(
+    py_callback: Py<PyAny>,
+    error_mode: PyCallbackErrorMode,
+) -> BeforeModelCallback {
+    let wrapper = PythonCallback::with_error_mode(py_callback, error_mode);
+    Box::new(
+        move |ctx: Arc<dyn CallbackContext>,
+              request: LlmRequest|
+              -> Pin<Box<dyn Future<Output = adk_core::Result<BeforeModelResult>> + Send>> {
+            let wrapper = wrapper.clone();
+            Box::pin(async move { wrapper.call_for_before_model(ctx, request).await })
+        },
+    )
+}
+
+/// Create an AfterModelCallback from a Python function
+pub fn create_after_model_callback(
+    py_callback: Py<PyAny>,
+    error_mode: PyCallbackErrorMode,
+) -> AfterModelCallback {
+    let wrapper = PythonCallback::with_error_mode(py_callback, error_mode);
+    Box::new(
+        move |ctx: Arc<dyn CallbackContext>, response: LlmResponse| -> Pin<
+            Box<dyn Future<Output = adk_core::Result<Option<LlmResponse>>> + Send>,
+        > {
+            let wrapper = wrapper.clone();
+            Box::pin(async move { wrapper.call_for_after_model(ctx, response).await })
+        },
+    )
+}
+
+/// Create a BeforeToolCallback from a Python function
+pub fn create_before_tool_callback(
+    py_callback: Py<PyAny>,
+    error_mode: PyCallbackErrorMode,
+) -> BeforeToolCallback {
+    let wrapper = PythonCallback::with_error_mode(py_callback, error_mode);
+    Box::new(
+        move |ctx: Arc<dyn CallbackContext>| -> Pin<
+            Box<dyn Future<Output = adk_core::Result<Option<Content>>> + Send>,
+        > {
+            let wrapper = wrapper.clone();
+            Box::pin(async move { wrapper.call_for_content(ctx).await })
+        },
+    )
+}
+
+/// Create an AfterToolCallback from a Python function
+pub fn create_after_tool_callback(
+    py_callback: Py<PyAny>,
+    error_mode: PyCallbackErrorMode,
+) -> AfterToolCallback {
+    let wrapper = PythonCallback::with_error_mode(py_callback, error_mode);
+    Box::new(
+        move |ctx: Arc<dyn CallbackContext>| -> Pin<
+            Box<dyn Future<Output = adk_core::Result<Option<Content>>> + Send>,
+        > {
+            let wrapper = wrapper.clone();
+            Box::pin(async move { wrapper.call_for_content(ctx).await })
+        },
+    )
+}
+
+// ============================================================================
+// Python-exposed types for callbacks
+// ============================================================================
+
+/// Python wrapper for LlmRequest
+///
+/// `model`, `contents`, and `config` are settable: a `before_model`
+/// callback can rewrite them and hand the request back via
+/// `BeforeModelResult.cont_with(request)` to actually change what reaches
+/// the LLM (prompt injection guards, model switching, dynamic tool
+/// pruning). `tools` isn't Python-constructible, so it always passes
+/// through unchanged.
+#[pyclass(name = "LlmRequest")]
+#[derive(Clone)]
+pub struct PyLlmRequest {
+    #[pyo3(get, set)]
+    pub model: String,
+    #[pyo3(get, set)]
+    pub contents: Vec<PyContent>,
+    #[pyo3(get, set)]
+    pub config: Option<PyGenerateContentConfig>,
+    tools: HashMap<String, Arc<dyn adk_core::Tool>>,
+}
+
+impl From<LlmRequest> for PyLlmRequest {
+    fn from(req: LlmRequest) -> Self {
+        Self {
+            model: req.model,
+            contents: req.contents.into_iter().map(PyContent::from).collect(),
+            config: req.config.map(PyGenerateContentConfig::from),
+            tools: req.tools,
+        }
+    }
+}
+
+impl PyLlmRequest {
+    /// Convert back to the Rust `LlmRequest`, carrying over whatever
+    /// `tools` the original request had.
+    fn into_llm_request(self) -> LlmRequest {
+        LlmRequest {
+            model: self.model,
+            contents: self.contents.into_iter().map(Into::into).collect(),
+            tools: self.tools,
+            config: self.config.map(Into::into),
+        }
+    }
+}
+
+#[pymethods]
+impl PyLlmRequest {
+    fn __repr__(&self) -> String {
+        format!(
+            "LlmRequest(model='{}', contents_count={})",
+            self.model,
+            self.contents.len()
+        )
+    }
+}
+
+/// Python wrapper for LlmResponse
+#[pyclass(name = "LlmResponse")]
+#[derive(Clone)]
+pub struct PyLlmResponse {
+    content: Option<PyContent>,
+    #[pyo3(get)]
+    pub partial: bool,
+    #[pyo3(get)]
+    pub turn_complete: bool,
+}
+
+impl From<LlmResponse> for PyLlmResponse {
+    fn from(resp: LlmResponse) -> Self {
+        Self {
+            content: resp.content.map(PyContent::from),
+            partial: resp.partial,
+            turn_complete: resp.turn_complete,
+        }
+    }
+}
+
+impl From<PyLlmResponse> for LlmResponse {
+    fn from(resp: PyLlmResponse) -> Self {
+        LlmResponse {
+            content: resp.content.map(|c| c.into()),
+            partial: resp.partial,
+            turn_complete: resp.turn_complete,
+            ..Default::default()
+        }
+    }
+}
+
+#[pymethods]
+impl PyLlmResponse {
+    #[new]
+    #[pyo3(signature = (content=None, partial=false, turn_complete=true))]
+    fn new(content: Option<PyContent>, partial: bool, turn_complete: bool) -> Self {
+        Self {
+            content,
+            partial,
+            turn_complete,
+        }
+    }
+
+    #[getter]
+    fn content(&self) -> Option<PyContent> {
+        self.content.clone()
+    }
+
+    fn get_text(&self) -> Option<String> {
+        self.content.as_ref().map(|c| c.extract_text())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "LlmResponse(partial={}, turn_complete={})",
+            self.partial, self.turn_complete
+        )
+    }
+}
+
+/// Python wrapper for BeforeModelResult
+#[pyclass(name = "BeforeModelResult")]
+#[derive(Clone)]
+pub struct PyBeforeModelResult {
+    skip: bool,
+    response_text: Option<String>,
+    rewritten_request: Option<PyLlmRequest>,
+}
+
+impl PyBeforeModelResult {
+    fn into_rust(self, request: LlmRequest) -> BeforeModelResult {
+        if self.skip {
+            let response = if let Some(text) = self.response_text {
+                LlmResponse::new(Content::new("model").with_text(&text))
+            } else {
+                LlmResponse::new(Content::new("model").with_text(""))
+            };
+            BeforeModelResult::Skip(response)
+        } else if let Some(rewritten) = self.rewritten_request {
+            BeforeModelResult::Continue(rewritten.into_llm_request())
+        } else {
+            BeforeModelResult::Continue(request)
+        }
+    }
+}
+
+#[pymethods]
+impl PyBeforeModelResult {
+    /// Continue with the model call, unchanged
+    #[staticmethod]
+    fn cont() -> Self {
+        Self {
+            skip: false,
+            response_text: None,
+            rewritten_request: None,
+        }
+    }
+
+    /// Skip the model call and return the given response text
+    #[staticmethod]
+    fn skip(response_text: String) -> Self {
+        Self {
+            skip: true,
+            response_text: Some(response_text),
+            rewritten_request: None,
+        }
+    }
+
+    /// Continue with the model call, but using `request` in place of the
+    /// one the callback was given
+    #[staticmethod]
+    fn cont_with(request: PyLlmRequest) -> Self {
+        Self {
+            skip: false,
+            response_text: None,
+            rewritten_request: Some(request),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        if self.skip {
+            format!(
+                "BeforeModelResult.skip('{}')",
+                self.response_text.as_deref().unwrap_or("")
+            )
+        } else if self.rewritten_request.is_some() {
+            "BeforeModelResult.cont_with(...)".to_string()
+        } else {
+            "BeforeModelResult.cont()".to_string()
+        }
+    }
+}
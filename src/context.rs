@@ -119,7 +119,12 @@ impl PyToolContext {
 pub struct PyInvocationContext {
     pub(crate) base: PyContext,
     pub(crate) user_content: Option<PyContent>,
-    pub(crate) state: PyState,
+    /// Shared with every `ctx.state` handed back to Python: the `#[getter]`
+    /// below returns `clone_ref`, the *same* underlying `PyState`, not a
+    /// fresh copy - so a write through one reference is visible to the
+    /// next read, and to `persistable_delta()` read back on this field
+    /// from Rust (see `agent/custom.rs`).
+    pub(crate) state: Py<PyState>,
 }
 
 #[pymethods]
@@ -155,10 +160,14 @@ impl PyInvocationContext {
         self.user_content.clone()
     }
 
-    /// Session state - can read values set by previous turns
+    /// Session state - reads values set by previous turns, and batches
+    /// any writes made this turn until the runner drains them into the
+    /// emitted event's state_delta. Every access returns a handle to the
+    /// same underlying `State`, so `ctx.state.set(...)` is visible to
+    /// later reads of `ctx.state` and to the runner's drain.
     #[getter]
-    fn state(&self) -> PyState {
-        self.state.clone()
+    fn state(&self, py: Python<'_>) -> Py<PyState> {
+        self.state.clone_ref(py)
     }
 
     fn __repr__(&self) -> String {
@@ -187,9 +196,20 @@ impl PyInvocationContext {
         Self {
             base: PyContext::from_readonly(ctx),
             user_content,
-            state,
+            state: Python::with_gil(|py| Py::new(py, state).expect("allocating a State object")),
         }
     }
+
+    /// The writes the handler recorded on `ctx.state` during this run, with
+    /// `temp:` keys stripped - see `PyState::persistable_delta`. Read back
+    /// after the Python call returns so `agent/custom.rs` can attach them
+    /// to the emitted event's `state_delta`.
+    pub fn persistable_delta(
+        &self,
+        py: Python<'_>,
+    ) -> std::collections::HashMap<String, serde_json::Value> {
+        self.state.borrow(py).persistable_delta()
+    }
 }
 
 /// Callback context passed to before/after callbacks
@@ -200,7 +220,10 @@ impl PyInvocationContext {
 pub struct PyCallbackContext {
     pub(crate) base: PyContext,
     pub(crate) user_content: Option<PyContent>,
-    pub(crate) state: PyState,
+    /// Shared with every `ctx.state` handed back to Python - see the same
+    /// field on `PyInvocationContext` for why this can't be a plain
+    /// `PyState` clone.
+    pub(crate) state: Py<PyState>,
 }
 
 #[pymethods]
@@ -236,10 +259,14 @@ impl PyCallbackContext {
         self.user_content.clone()
     }
 
-    /// Session state - can read values set by previous turns
+    /// Session state - reads values set by previous turns, and batches
+    /// any writes made this turn until the callback wrapper drains them
+    /// into the invocation's state delta. Every access returns a handle to
+    /// the same underlying `State`, so `ctx.state.set(...)` is visible to
+    /// later reads of `ctx.state` and to that drain.
     #[getter]
-    fn state(&self) -> PyState {
-        self.state.clone()
+    fn state(&self, py: Python<'_>) -> Py<PyState> {
+        self.state.clone_ref(py)
     }
 
     fn __repr__(&self) -> String {
@@ -256,13 +283,26 @@ impl PyCallbackContext {
         // Get user content from user_content() method (from ReadonlyContext)
         let user_content = Some(PyContent::from(ctx.user_content().clone()));
 
-        // CallbackContext doesn't have session access, create empty state
+        // CallbackContext doesn't expose session access, so there's no base
+        // snapshot to read from - but the state still tracks pending writes
+        // so a callback's mutations reach the emitted event's state_delta.
         let state = PyState::empty();
 
         Self {
             base: PyContext::from_readonly(ctx),
             user_content,
-            state,
+            state: Python::with_gil(|py| Py::new(py, state).expect("allocating a State object")),
         }
     }
+
+    /// The writes a callback recorded on `ctx.state` during this call,
+    /// with `temp:` keys stripped - see `PyState::persistable_delta`. Read
+    /// back after the Python call returns so the wrapper in `callbacks.rs`
+    /// can fold them into the delta the invocation ends up persisting.
+    pub fn persistable_delta(
+        &self,
+        py: Python<'_>,
+    ) -> std::collections::HashMap<String, serde_json::Value> {
+        self.state.borrow(py).persistable_delta()
+    }
 }
@@ -1,9 +1,94 @@
 //! Error types for Python bindings
 
+use pyo3::create_exception;
+use pyo3::exceptions::PyException;
 use pyo3::prelude::*;
-use pyo3::exceptions::PyRuntimeError;
 use thiserror::Error;
 
+// ============================================================================
+// Python exception hierarchy
+// ============================================================================
+
+create_exception!(
+    _adk_rust,
+    AdkError,
+    PyException,
+    "Base class for all exceptions raised by adk-rust."
+);
+create_exception!(
+    _adk_rust,
+    ModelError,
+    AdkError,
+    "Raised when an LLM provider call fails."
+);
+create_exception!(
+    _adk_rust,
+    ToolError,
+    AdkError,
+    "Raised when a tool invocation fails."
+);
+create_exception!(
+    _adk_rust,
+    SerializationError,
+    AdkError,
+    "Raised when converting data between Rust and Python fails."
+);
+create_exception!(
+    _adk_rust,
+    TimeoutError,
+    AdkError,
+    "Raised when an operation exceeds its deadline."
+);
+create_exception!(
+    _adk_rust,
+    PythonError,
+    AdkError,
+    "Raised when a Python callback or handler itself raised an exception."
+);
+create_exception!(
+    _adk_rust,
+    SessionError,
+    AdkError,
+    "Raised when a session service operation (create/get/list/delete) fails."
+);
+create_exception!(
+    _adk_rust,
+    ConnectionError,
+    AdkError,
+    "Raised when connecting to an external service (e.g. an MCP server) fails."
+);
+
+/// Register the exception hierarchy on the module.
+pub fn register_exceptions(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add("AdkError", m.py().get_type_bound::<AdkError>())?;
+    m.add("ModelError", m.py().get_type_bound::<ModelError>())?;
+    m.add("ToolError", m.py().get_type_bound::<ToolError>())?;
+    m.add(
+        "SerializationError",
+        m.py().get_type_bound::<SerializationError>(),
+    )?;
+    m.add("TimeoutError", m.py().get_type_bound::<TimeoutError>())?;
+    m.add("PythonError", m.py().get_type_bound::<PythonError>())?;
+    m.add("SessionError", m.py().get_type_bound::<SessionError>())?;
+    m.add(
+        "ConnectionError",
+        m.py().get_type_bound::<ConnectionError>(),
+    )?;
+    Ok(())
+}
+
+/// Convert a core ADK error into the matching Python exception subclass.
+pub fn adk_error_to_pyerr(err: &adk_core::AdkError) -> PyErr {
+    let message = err.to_string();
+    match err {
+        adk_core::AdkError::Model(_) => ModelError::new_err(message),
+        adk_core::AdkError::Tool(_) => ToolError::new_err(message),
+        adk_core::AdkError::Timeout(_) => TimeoutError::new_err(message),
+        adk_core::AdkError::Agent(_) => AdkError::new_err(message),
+        _ => AdkError::new_err(message),
+    }
+}
+
 #[derive(Error, Debug)]
 pub enum AdkPyError {
     #[error("ADK error: {0}")]
@@ -18,7 +103,11 @@ pub enum AdkPyError {
 
 impl From<AdkPyError> for PyErr {
     fn from(err: AdkPyError) -> PyErr {
-        PyRuntimeError::new_err(err.to_string())
+        match err {
+            AdkPyError::Adk(ref adk_err) => adk_error_to_pyerr(adk_err),
+            AdkPyError::Python(msg) => PythonError::new_err(msg),
+            AdkPyError::Serialization(e) => SerializationError::new_err(e.to_string()),
+        }
     }
 }
 
@@ -28,38 +117,4 @@ impl From<PyErr> for AdkPyError {
     }
 }
 
-/// Python-visible error class
-#[pyclass(name = "AdkError")]
-#[derive(Clone)]
-pub struct PyAdkError {
-    message: String,
-}
-
-#[pymethods]
-impl PyAdkError {
-    #[new]
-    fn new(message: String) -> Self {
-        Self { message }
-    }
-
-    fn __str__(&self) -> String {
-        self.message.clone()
-    }
-
-    fn __repr__(&self) -> String {
-        format!("AdkError('{}')", self.message)
-    }
-
-    #[getter]
-    fn message(&self) -> String {
-        self.message.clone()
-    }
-}
-
-impl From<adk_core::AdkError> for PyAdkError {
-    fn from(err: adk_core::AdkError) -> Self {
-        Self { message: err.to_string() }
-    }
-}
-
 pub type PyResult<T> = Result<T, AdkPyError>;
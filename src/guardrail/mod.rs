@@ -5,7 +5,8 @@ use adk_guardrail::{
     Severity,
 };
 use pyo3::prelude::*;
-use std::sync::Arc;
+use regex::Regex;
+use std::sync::{Arc, OnceLock};
 
 use crate::types::PyContent;
 
@@ -43,7 +44,7 @@ impl From<Severity> for PySeverity {
 
 /// PII types for redaction
 #[pyclass(name = "PiiType", eq)]
-#[derive(Clone, Copy, PartialEq, Eq)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum PyPiiType {
     Email,
     Phone,
@@ -130,13 +131,151 @@ impl PyContentFilter {
     }
 }
 
+/// The regex used to detect a given `PiiType` when computing structured
+/// redaction spans. Kept local to the bindings crate since the upstream
+/// `PiiRedactor` only exposes a redacted string, not match positions.
+fn pattern_for(pii_type: PyPiiType) -> &'static Regex {
+    match pii_type {
+        PyPiiType::Email => {
+            static RE: OnceLock<Regex> = OnceLock::new();
+            RE.get_or_init(|| Regex::new(r"[\w.%+-]+@[\w.-]+\.[A-Za-z]{2,}").unwrap())
+        }
+        PyPiiType::Phone => {
+            static RE: OnceLock<Regex> = OnceLock::new();
+            RE.get_or_init(|| Regex::new(r"\b\d{3}[-.\s]\d{3}[-.\s]\d{4}\b").unwrap())
+        }
+        PyPiiType::Ssn => {
+            static RE: OnceLock<Regex> = OnceLock::new();
+            RE.get_or_init(|| Regex::new(r"\b\d{3}-\d{2}-\d{4}\b").unwrap())
+        }
+        PyPiiType::CreditCard => {
+            static RE: OnceLock<Regex> = OnceLock::new();
+            RE.get_or_init(|| Regex::new(r"\b(?:\d[ -]?){13,16}\b").unwrap())
+        }
+        PyPiiType::IpAddress => {
+            static RE: OnceLock<Regex> = OnceLock::new();
+            RE.get_or_init(|| Regex::new(r"\b(?:\d{1,3}\.){3}\d{1,3}\b").unwrap())
+        }
+    }
+}
+
+/// Tie-break rank used when two matches of different PII types overlap —
+/// higher wins alongside "longest match" in `compute_redaction_spans`.
+fn severity_rank(pii_type: PyPiiType) -> u8 {
+    match pii_type {
+        PyPiiType::Ssn => 4,
+        PyPiiType::CreditCard => 3,
+        PyPiiType::Phone => 2,
+        PyPiiType::Email => 1,
+        PyPiiType::IpAddress => 0,
+    }
+}
+
+/// Map a char index into `text` to its byte offset (the string's byte
+/// length if the index is at or past the end). Spans are reported and
+/// consumed in char indices throughout this module, never byte offsets.
+fn char_to_byte(text: &str, char_idx: usize) -> usize {
+    text.char_indices()
+        .nth(char_idx)
+        .map(|(b, _)| b)
+        .unwrap_or(text.len())
+}
+
+fn compute_redaction_spans(text: &str, pii_types: &[PyPiiType]) -> Vec<PyRedactionSpan> {
+    struct Candidate {
+        pii_type: PyPiiType,
+        start_char: usize,
+        end_char: usize,
+        replacement: String,
+    }
+
+    let mut candidates: Vec<Candidate> = Vec::new();
+    for &pii_type in pii_types {
+        for m in pattern_for(pii_type).find_iter(text) {
+            let start_char = text[..m.start()].chars().count();
+            let end_char = start_char + m.as_str().chars().count();
+            candidates.push(Candidate {
+                pii_type,
+                start_char,
+                end_char,
+                replacement: format!("[REDACTED:{:?}]", pii_type).to_uppercase(),
+            });
+        }
+    }
+
+    // Longest match first, ties broken by severity, so the overlap pass
+    // below always keeps the highest-severity/longest match and drops the
+    // nested one.
+    candidates.sort_by(|a, b| {
+        let len_a = a.end_char - a.start_char;
+        let len_b = b.end_char - b.start_char;
+        len_b
+            .cmp(&len_a)
+            .then_with(|| severity_rank(b.pii_type).cmp(&severity_rank(a.pii_type)))
+    });
+
+    let mut kept: Vec<Candidate> = Vec::new();
+    for candidate in candidates {
+        let overlaps = kept
+            .iter()
+            .any(|k| candidate.start_char < k.end_char && k.start_char < candidate.end_char);
+        if !overlaps {
+            kept.push(candidate);
+        }
+    }
+
+    kept.sort_by_key(|c| c.start_char);
+    kept.into_iter()
+        .map(|c| PyRedactionSpan {
+            start: c.start_char,
+            end: c.end_char,
+            pii_type: c.pii_type,
+            replacement: c.replacement,
+        })
+        .collect()
+}
+
+/// A single redaction match: char offsets into the *original* text, the
+/// `PiiType` detected, and the replacement string substituted in its place.
+#[pyclass(name = "RedactionSpan")]
+#[derive(Clone)]
+pub struct PyRedactionSpan {
+    #[pyo3(get)]
+    pub start: usize,
+    #[pyo3(get)]
+    pub end: usize,
+    #[pyo3(get)]
+    pub pii_type: PyPiiType,
+    #[pyo3(get)]
+    pub replacement: String,
+}
+
+#[pymethods]
+impl PyRedactionSpan {
+    fn __repr__(&self) -> String {
+        format!(
+            "RedactionSpan(start={}, end={}, pii_type={:?}, replacement='{}')",
+            self.start, self.end, self.pii_type, self.replacement
+        )
+    }
+}
+
 /// PII detection and redaction guardrail
 #[pyclass(name = "PiiRedactor")]
 #[derive(Clone)]
 pub struct PyPiiRedactor {
     pub(crate) inner: Arc<PiiRedactor>,
+    pii_types: Vec<PyPiiType>,
 }
 
+const ALL_PII_TYPES: [PyPiiType; 5] = [
+    PyPiiType::Email,
+    PyPiiType::Phone,
+    PyPiiType::Ssn,
+    PyPiiType::CreditCard,
+    PyPiiType::IpAddress,
+];
+
 #[pymethods]
 impl PyPiiRedactor {
     /// Create a new PII redactor with all PII types enabled (Email, Phone, SSN, CreditCard)
@@ -144,15 +283,17 @@ impl PyPiiRedactor {
     fn new() -> Self {
         Self {
             inner: Arc::new(PiiRedactor::new()),
+            pii_types: ALL_PII_TYPES.to_vec(),
         }
     }
 
     /// Create a PII redactor with specific types
     #[staticmethod]
     fn with_types(types: Vec<PyPiiType>) -> Self {
-        let pii_types: Vec<PiiType> = types.into_iter().map(Into::into).collect();
+        let pii_types: Vec<PiiType> = types.iter().copied().map(Into::into).collect();
         Self {
             inner: Arc::new(PiiRedactor::with_types(&pii_types)),
+            pii_types: types,
         }
     }
 
@@ -162,6 +303,231 @@ impl PyPiiRedactor {
         let type_names: Vec<String> = types.iter().map(|t| format!("{:?}", t)).collect();
         (redacted, type_names)
     }
+
+    /// Redact PII from text, returning the full structured match list
+    /// instead of just the redacted string.
+    ///
+    /// Each span carries char `start`/`end` offsets into the *original*
+    /// text, the `PiiType`, and the replacement substituted for it, so a
+    /// Python UI can highlight detected PII in place and auditing code can
+    /// see exactly what changed. When matches overlap, the
+    /// highest-severity/longest one wins and the nested match is dropped.
+    /// Use `PiiRedactor.apply()` to turn a (possibly filtered) span list
+    /// back into a string.
+    fn redact_spans(&self, text: &str) -> Vec<PyRedactionSpan> {
+        compute_redaction_spans(text, &self.pii_types)
+    }
+
+    /// Rebuild a string from `original` by substituting each span's
+    /// replacement over its `[start, end)` char range.
+    ///
+    /// Passing a subset of the spans returned by `redact_spans` selectively
+    /// un-redacts the rest (e.g. keep the email span out to leave it
+    /// readable while still masking the SSN span).
+    #[staticmethod]
+    fn apply(original: &str, spans: Vec<PyRedactionSpan>) -> String {
+        let mut ordered = spans;
+        ordered.sort_by_key(|s| s.start);
+
+        let mut result = String::with_capacity(original.len());
+        let mut last_byte = 0usize;
+        for span in &ordered {
+            let start_byte = char_to_byte(original, span.start);
+            let end_byte = char_to_byte(original, span.end);
+            if start_byte < last_byte {
+                // Overlapping input spans; skip rather than corrupt the output.
+                continue;
+            }
+            result.push_str(&original[last_byte..start_byte]);
+            result.push_str(&span.replacement);
+            last_byte = end_byte;
+        }
+        result.push_str(&original[last_byte..]);
+        result
+    }
+}
+
+/// Upper bound on how many chars a match of `pii_type` could still consume,
+/// i.e. how big a trailing window must be held back so a match straddling
+/// two streamed deltas isn't flushed before it's complete.
+fn max_pattern_len(pii_type: PyPiiType) -> usize {
+    match pii_type {
+        PyPiiType::Email => 254,     // RFC 5321 max mailbox length
+        PyPiiType::Phone => 14,      // e.g. "123-456-7890"
+        PyPiiType::Ssn => 11,        // "123-45-6789"
+        PyPiiType::CreditCard => 32, // 16 digits, each with an optional separator
+        PyPiiType::IpAddress => 15,  // "255.255.255.255"
+    }
+}
+
+/// Incrementally redacts PII from a sequence of streamed text deltas.
+///
+/// Maintains a buffer and a "committed offset" into the logical stream:
+/// after each delta, it re-runs detection over the buffer and flushes the
+/// prefix that cannot possibly be part of a longer pending match — i.e. it
+/// holds back a trailing window equal to the longest PII pattern any
+/// enabled type could still be in the middle of. Spans are reported using
+/// absolute offsets into the full logical stream, so callers see one
+/// consistent coordinate space across chunks.
+#[pyclass(name = "StreamingPiiRedactor")]
+pub struct PyStreamingPiiRedactor {
+    pii_types: Vec<PyPiiType>,
+    buffer: String,
+    committed_offset: usize,
+}
+
+#[pymethods]
+impl PyStreamingPiiRedactor {
+    /// Create a streaming redactor with all PII types enabled.
+    #[new]
+    fn new() -> Self {
+        Self {
+            pii_types: ALL_PII_TYPES.to_vec(),
+            buffer: String::new(),
+            committed_offset: 0,
+        }
+    }
+
+    /// Create a streaming redactor for specific types only.
+    #[staticmethod]
+    fn with_types(types: Vec<PyPiiType>) -> Self {
+        Self {
+            pii_types: types,
+            buffer: String::new(),
+            committed_offset: 0,
+        }
+    }
+
+    /// Append a streamed text delta and return `(safe_text, spans)`:
+    /// `safe_text` is the redacted chunk now safe to flush to the caller,
+    /// and `spans` are the redaction spans settled this round, in absolute
+    /// offsets into the full logical stream.
+    fn push(&mut self, delta: &str) -> (String, Vec<PyRedactionSpan>) {
+        self.buffer.push_str(delta);
+        self.flush_safe_prefix(false)
+    }
+
+    /// Flush whatever remains in the buffer at stream end, since nothing
+    /// more can arrive to complete a pending match.
+    fn finish(&mut self) -> (String, Vec<PyRedactionSpan>) {
+        self.flush_safe_prefix(true)
+    }
+}
+
+impl PyStreamingPiiRedactor {
+    fn flush_safe_prefix(&mut self, flush_all: bool) -> (String, Vec<PyRedactionSpan>) {
+        let spans = compute_redaction_spans(&self.buffer, &self.pii_types);
+        let total_chars = self.buffer.chars().count();
+
+        let mut flush_len = if flush_all {
+            total_chars
+        } else {
+            let holdback = self
+                .pii_types
+                .iter()
+                .map(|&t| max_pattern_len(t))
+                .max()
+                .unwrap_or(0);
+            total_chars.saturating_sub(holdback)
+        };
+
+        if !flush_all {
+            // A match starting before the cut but ending after it must stay
+            // buffered whole; pull the cut back before its start instead of
+            // flushing it in pieces.
+            while let Some(crossing) = spans
+                .iter()
+                .find(|s| s.start < flush_len && s.end > flush_len)
+            {
+                flush_len = crossing.start;
+            }
+        }
+
+        let settled: Vec<PyRedactionSpan> = spans
+            .into_iter()
+            .filter(|s| s.end <= flush_len)
+            .collect();
+
+        let prefix_byte_len = char_to_byte(&self.buffer, flush_len);
+        let prefix_text = self.buffer[..prefix_byte_len].to_string();
+        let redacted = PyPiiRedactor::apply(&prefix_text, settled.clone());
+
+        let absolute_spans: Vec<PyRedactionSpan> = settled
+            .into_iter()
+            .map(|s| PyRedactionSpan {
+                start: s.start + self.committed_offset,
+                end: s.end + self.committed_offset,
+                pii_type: s.pii_type,
+                replacement: s.replacement,
+            })
+            .collect();
+
+        self.buffer = self.buffer[prefix_byte_len..].to_string();
+        self.committed_offset += flush_len;
+
+        (redacted, absolute_spans)
+    }
+}
+
+/// Incrementally checks a sequence of streamed text deltas against blocked
+/// keywords, catching a keyword that straddles two chunks.
+///
+/// Uses the same buffer-and-holdback approach as `StreamingPiiRedactor`,
+/// without a committed offset since it reports no spans: it holds back a trailing window equal to the longest blocked keyword minus
+/// one char, since anything shorter than that couldn't still be growing
+/// into a match.
+#[pyclass(name = "StreamingContentFilter")]
+pub struct PyStreamingContentFilter {
+    blocked_keywords: Vec<String>,
+    buffer: String,
+}
+
+#[pymethods]
+impl PyStreamingContentFilter {
+    #[new]
+    fn new(blocked_keywords: Vec<String>) -> Self {
+        Self {
+            blocked_keywords,
+            buffer: String::new(),
+        }
+    }
+
+    /// Append a delta and return the text now safe to flush. Raises
+    /// `ValueError` the moment a blocked keyword is detected in the buffer.
+    fn push(&mut self, delta: &str) -> PyResult<String> {
+        self.buffer.push_str(delta);
+
+        let lower = self.buffer.to_lowercase();
+        if let Some(keyword) = self
+            .blocked_keywords
+            .iter()
+            .find(|k| lower.contains(&k.to_lowercase()))
+        {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "blocked keyword detected: '{keyword}'"
+            )));
+        }
+
+        let holdback = self
+            .blocked_keywords
+            .iter()
+            .map(|k| k.chars().count())
+            .max()
+            .unwrap_or(1)
+            .saturating_sub(1);
+        let total_chars = self.buffer.chars().count();
+        let flush_len = total_chars.saturating_sub(holdback);
+
+        let byte_len = char_to_byte(&self.buffer, flush_len);
+        let flushed = self.buffer[..byte_len].to_string();
+        self.buffer = self.buffer[byte_len..].to_string();
+        Ok(flushed)
+    }
+
+    /// Flush whatever remains in the buffer at stream end.
+    fn finish(&mut self) -> String {
+        std::mem::take(&mut self.buffer)
+    }
 }
 
 /// A set of guardrails to run together
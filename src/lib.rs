@@ -12,6 +12,8 @@
 //! - `types` - Core types (Content, Part, Event)
 //! - `context` - Execution context types
 //! - `error` - Error types
+//! - `telemetry` - Tracing/observability spans
+//! - `promise` - Rust-side async work exposed to Python as a poll/await-able handle
 
 use pyo3::prelude::*;
 
@@ -23,8 +25,10 @@ pub mod error;
 pub mod guardrail;
 pub mod memory;
 pub mod model;
+pub mod promise;
 pub mod runner;
 pub mod session;
+pub mod telemetry;
 pub mod tool;
 pub mod types;
 
@@ -51,6 +55,8 @@ fn _adk_rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyGroqModel>()?;
     m.add_class::<PyOllamaModel>()?;
     m.add_class::<PyMockLlm>()?;
+    m.add_class::<PyMockLlmBuilder>()?;
+    m.add_class::<PyModel>()?;
 
     // Agents
     m.add_class::<PyLlmAgent>()?;
@@ -63,6 +69,7 @@ fn _adk_rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<agent::PyConditionalAgent>()?;
     m.add_class::<agent::PyLlmConditionalAgent>()?;
     m.add_class::<agent::PyLlmConditionalAgentBuilder>()?;
+    m.add_class::<agent::PyFallbackAgent>()?;
 
     // Tools
     m.add_class::<PyFunctionTool>()?;
@@ -73,16 +80,20 @@ fn _adk_rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<tool::PyAgentTool>()?;
     m.add_class::<tool::PyMcpToolset>()?;
     m.add_class::<tool::PyMcpToolWrapper>()?;
+    m.add_class::<tool::PyParallelAgentTool>()?;
 
     // Session
     m.add_class::<PyInMemorySessionService>()?;
+    m.add_class::<session::PySqliteSessionService>()?;
     m.add_class::<PySession>()?;
+    m.add_class::<session::PySubscription>()?;
     m.add_class::<PyState>()?;
     m.add_class::<PyRunConfig>()?;
     m.add_class::<PyStreamingMode>()?;
     m.add_class::<PyCreateSessionRequest>()?;
     m.add_class::<PyGetSessionRequest>()?;
     m.add_class::<PyListSessionRequest>()?;
+    m.add_class::<session::PySessionPage>()?;
     m.add_class::<PyDeleteSessionRequest>()?;
     m.add_class::<session::PyGenerateContentConfig>()?;
 
@@ -101,15 +112,19 @@ fn _adk_rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<callbacks::PyLlmRequest>()?;
     m.add_class::<callbacks::PyLlmResponse>()?;
     m.add_class::<callbacks::PyBeforeModelResult>()?;
+    m.add_class::<callbacks::PyCallbackErrorMode>()?;
 
     // Error
-    m.add_class::<error::PyAdkError>()?;
+    error::register_exceptions(m)?;
 
     // Guardrails
     m.add_class::<guardrail::PySeverity>()?;
     m.add_class::<guardrail::PyPiiType>()?;
     m.add_class::<guardrail::PyContentFilter>()?;
     m.add_class::<guardrail::PyPiiRedactor>()?;
+    m.add_class::<guardrail::PyRedactionSpan>()?;
+    m.add_class::<guardrail::PyStreamingPiiRedactor>()?;
+    m.add_class::<guardrail::PyStreamingContentFilter>()?;
     m.add_class::<guardrail::PyGuardrailSet>()?;
     m.add_class::<guardrail::PyGuardrailResult>()?;
     m.add_class::<guardrail::PyGuardrailFailure>()?;
@@ -121,6 +136,22 @@ fn _adk_rust(m: &Bound<'_, PyModule>) -> PyResult<()> {
 
     // Artifact
     m.add_class::<artifact::PyInMemoryArtifactService>()?;
+    m.add_class::<artifact::PyFileSystemArtifactService>()?;
+    m.add_class::<artifact::PyS3ArtifactService>()?;
+    m.add_class::<artifact::PyGcsArtifactService>()?;
+    m.add_class::<artifact::PyTextChange>()?;
+    m.add_class::<artifact::PyArtifactChunkStream>()?;
+    m.add_class::<artifact::PyArtifactView>()?;
+
+    // Telemetry
+    m.add_class::<telemetry::PySpan>()?;
+    m.add_function(wrap_pyfunction!(telemetry::enable_tracing, m)?)?;
+    m.add_class::<telemetry::PyLoggingHandle>()?;
+    m.add_function(wrap_pyfunction!(telemetry::init, m)?)?;
+
+    // Promise
+    m.add_class::<promise::PyPromise>()?;
+    m.add_class::<promise::PyDriver>()?;
 
     Ok(())
 }
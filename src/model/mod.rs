@@ -1,319 +1,839 @@
-//! Model bindings for Python - All LLM providers
-//!
-//! This module provides Python bindings for LLM providers:
-//! - `GeminiModel` - Google Gemini
-//! - `OpenAIModel` - OpenAI GPT models
-//! - `AnthropicModel` - Anthropic Claude models
-//! - `DeepSeekModel` - DeepSeek models
-//! - `GroqModel` - Groq (fast inference)
-//! - `OllamaModel` - Local Ollama models
-//! - `MockLlm` - Mock for testing
-
-use pyo3::prelude::*;
-use std::sync::Arc;
-
-/// Google Gemini model wrapper
-#[pyclass(name = "GeminiModel")]
-#[derive(Clone)]
-pub struct PyGeminiModel {
-    pub(crate) inner: Arc<dyn adk_core::Llm>,
-}
-
-#[pymethods]
-impl PyGeminiModel {
-    #[new]
-    #[pyo3(signature = (api_key, model="gemini-2.5-flash"))]
-    fn new(api_key: String, model: &str) -> PyResult<Self> {
-        let gemini = adk_model::GeminiModel::new(&api_key, model)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(gemini),
-        })
-    }
-
-    #[getter]
-    fn name(&self) -> String {
-        self.inner.name().to_string()
-    }
-
-    fn __repr__(&self) -> String {
-        format!("GeminiModel(name='{}')", self.name())
-    }
-}
-
-/// OpenAI model wrapper
-#[pyclass(name = "OpenAIModel")]
-#[derive(Clone)]
-pub struct PyOpenAIModel {
-    pub(crate) inner: Arc<dyn adk_core::Llm>,
-}
-
-#[pymethods]
-impl PyOpenAIModel {
-    #[new]
-    #[pyo3(signature = (api_key, model="gpt-4o"))]
-    fn new(api_key: String, model: &str) -> PyResult<Self> {
-        let config = adk_model::OpenAIConfig::new(&api_key, model);
-        let client = adk_model::OpenAIClient::new(config)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(client),
-        })
-    }
-
-    #[staticmethod]
-    fn compatible(api_key: String, base_url: String, model: String) -> PyResult<Self> {
-        let client = adk_model::OpenAIClient::compatible(&api_key, &base_url, &model)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(client),
-        })
-    }
-
-    #[getter]
-    fn name(&self) -> String {
-        self.inner.name().to_string()
-    }
-
-    fn __repr__(&self) -> String {
-        format!("OpenAIModel(name='{}')", self.name())
-    }
-}
-
-/// Anthropic Claude model wrapper
-#[pyclass(name = "AnthropicModel")]
-#[derive(Clone)]
-pub struct PyAnthropicModel {
-    pub(crate) inner: Arc<dyn adk_core::Llm>,
-}
-
-#[pymethods]
-impl PyAnthropicModel {
-    #[new]
-    #[pyo3(signature = (api_key, model="claude-sonnet-4-20250514"))]
-    fn new(api_key: String, model: &str) -> PyResult<Self> {
-        let config = adk_model::anthropic::AnthropicConfig::new(&api_key, model);
-        let client = adk_model::AnthropicClient::new(config)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(client),
-        })
-    }
-
-    #[staticmethod]
-    fn from_api_key(api_key: String) -> PyResult<Self> {
-        let client = adk_model::AnthropicClient::from_api_key(&api_key)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(client),
-        })
-    }
-
-    #[getter]
-    fn name(&self) -> String {
-        self.inner.name().to_string()
-    }
-
-    fn __repr__(&self) -> String {
-        format!("AnthropicModel(name='{}')", self.name())
-    }
-}
-
-/// DeepSeek model wrapper
-#[pyclass(name = "DeepSeekModel")]
-#[derive(Clone)]
-pub struct PyDeepSeekModel {
-    pub(crate) inner: Arc<dyn adk_core::Llm>,
-}
-
-#[pymethods]
-impl PyDeepSeekModel {
-    #[new]
-    fn new(api_key: String, model: String) -> PyResult<Self> {
-        let config = adk_model::DeepSeekConfig::new(&api_key, &model);
-        let client = adk_model::DeepSeekClient::new(config)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(client),
-        })
-    }
-
-    #[staticmethod]
-    fn chat(api_key: String) -> PyResult<Self> {
-        let client = adk_model::DeepSeekClient::chat(&api_key)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(client),
-        })
-    }
-
-    #[staticmethod]
-    fn reasoner(api_key: String) -> PyResult<Self> {
-        let client = adk_model::DeepSeekClient::reasoner(&api_key)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(client),
-        })
-    }
-
-    #[getter]
-    fn name(&self) -> String {
-        self.inner.name().to_string()
-    }
-
-    fn __repr__(&self) -> String {
-        format!("DeepSeekModel(name='{}')", self.name())
-    }
-}
-
-/// Groq model wrapper (fast inference)
-#[pyclass(name = "GroqModel")]
-#[derive(Clone)]
-pub struct PyGroqModel {
-    pub(crate) inner: Arc<dyn adk_core::Llm>,
-}
-
-#[pymethods]
-impl PyGroqModel {
-    #[new]
-    fn new(api_key: String, model: String) -> PyResult<Self> {
-        let config = adk_model::GroqConfig::new(&api_key, &model);
-        let client = adk_model::GroqClient::new(config)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(client),
-        })
-    }
-
-    #[staticmethod]
-    fn llama70b(api_key: String) -> PyResult<Self> {
-        let client = adk_model::GroqClient::llama70b(&api_key)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(client),
-        })
-    }
-
-    #[staticmethod]
-    fn llama8b(api_key: String) -> PyResult<Self> {
-        let client = adk_model::GroqClient::llama8b(&api_key)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(client),
-        })
-    }
-
-    #[staticmethod]
-    fn mixtral(api_key: String) -> PyResult<Self> {
-        let client = adk_model::GroqClient::mixtral(&api_key)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(client),
-        })
-    }
-
-    #[getter]
-    fn name(&self) -> String {
-        self.inner.name().to_string()
-    }
-
-    fn __repr__(&self) -> String {
-        format!("GroqModel(name='{}')", self.name())
-    }
-}
-
-/// Ollama model wrapper (local inference)
-#[pyclass(name = "OllamaModel")]
-#[derive(Clone)]
-pub struct PyOllamaModel {
-    pub(crate) inner: Arc<dyn adk_core::Llm>,
-}
-
-#[pymethods]
-impl PyOllamaModel {
-    #[new]
-    fn new(model: String) -> PyResult<Self> {
-        let ollama = adk_model::OllamaModel::from_model(&model)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(ollama),
-        })
-    }
-
-    #[staticmethod]
-    fn with_host(host: String, model: String) -> PyResult<Self> {
-        let config = adk_model::OllamaConfig::with_host(&host, &model);
-        let ollama = adk_model::OllamaModel::new(config)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-        Ok(Self {
-            inner: Arc::new(ollama),
-        })
-    }
-
-    #[getter]
-    fn name(&self) -> String {
-        self.inner.name().to_string()
-    }
-
-    fn __repr__(&self) -> String {
-        format!("OllamaModel(name='{}')", self.name())
-    }
-}
-
-/// Mock LLM for testing
-#[pyclass(name = "MockLlm")]
-#[derive(Clone)]
-pub struct PyMockLlm {
-    pub(crate) inner: Arc<dyn adk_core::Llm>,
-}
-
-#[pymethods]
-impl PyMockLlm {
-    #[new]
-    #[pyo3(signature = (name, response_text="Mock response"))]
-    fn new(name: String, response_text: &str) -> Self {
-        let response =
-            adk_core::LlmResponse::new(adk_core::Content::new("model").with_text(response_text));
-        let mock = adk_model::MockLlm::new(&name).with_response(response);
-        Self {
-            inner: Arc::new(mock),
-        }
-    }
-
-    #[getter]
-    fn name(&self) -> String {
-        self.inner.name().to_string()
-    }
-
-    fn __repr__(&self) -> String {
-        format!("MockLlm(name='{}')", self.name())
-    }
-}
-
-/// Helper to extract an Llm trait object from any model type
-pub fn extract_llm(obj: &Bound<'_, PyAny>) -> PyResult<Arc<dyn adk_core::Llm>> {
-    if let Ok(model) = obj.extract::<PyRef<'_, PyGeminiModel>>() {
-        return Ok(model.inner.clone());
-    }
-    if let Ok(model) = obj.extract::<PyRef<'_, PyOpenAIModel>>() {
-        return Ok(model.inner.clone());
-    }
-    if let Ok(model) = obj.extract::<PyRef<'_, PyAnthropicModel>>() {
-        return Ok(model.inner.clone());
-    }
-    if let Ok(model) = obj.extract::<PyRef<'_, PyDeepSeekModel>>() {
-        return Ok(model.inner.clone());
-    }
-    if let Ok(model) = obj.extract::<PyRef<'_, PyGroqModel>>() {
-        return Ok(model.inner.clone());
-    }
-    if let Ok(model) = obj.extract::<PyRef<'_, PyOllamaModel>>() {
-        return Ok(model.inner.clone());
-    }
-    if let Ok(model) = obj.extract::<PyRef<'_, PyMockLlm>>() {
-        return Ok(model.inner.clone());
-    }
-    Err(pyo3::exceptions::PyTypeError::new_err(
-        "Expected a model type (GeminiModel, OpenAIModel, AnthropicModel, DeepSeekModel, GroqModel, OllamaModel, or MockLlm)",
-    ))
-}
+//! Model bindings for Python - All LLM providers
+//!
+//! This module provides Python bindings for LLM providers:
+//! - `GeminiModel` - Google Gemini
+//! - `OpenAIModel` - OpenAI GPT models
+//! - `AnthropicModel` - Anthropic Claude models
+//! - `DeepSeekModel` - DeepSeek models
+//! - `GroqModel` - Groq (fast inference)
+//! - `OllamaModel` - Local Ollama models
+//! - `MockLlm` - Mock for testing, with a `.scripted()` multi-turn builder
+//! - `Model` - Config-driven factory over a flat provider registry
+
+use async_trait::async_trait;
+use futures::{Stream, StreamExt};
+use pyo3::prelude::*;
+use pyo3::types::PyDict;
+use serde::Deserialize;
+use std::collections::{HashMap, VecDeque};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex};
+
+use crate::runner::PyEventStream;
+use crate::tool::PyFunctionTool;
+use crate::types::PyContent;
+
+/// Call `generate_content(..., stream=true)` on `llm` and forward each
+/// chunk to Python as a `PyEvent` as it arrives, rather than collecting the
+/// whole response first. Shared by every model wrapper's `generate_stream`
+/// so the provider-specific classes stay thin.
+fn generate_stream_for(
+    llm: Arc<dyn adk_core::Llm>,
+    contents: Vec<PyContent>,
+    tools: Option<Vec<PyRef<'_, PyFunctionTool>>>,
+) -> PyEventStream {
+    let model_name = llm.name().to_string();
+    let rust_contents: Vec<adk_core::Content> = contents.into_iter().map(Into::into).collect();
+
+    let mut tool_map: HashMap<String, Arc<dyn adk_core::Tool>> = HashMap::new();
+    for tool in tools.into_iter().flatten() {
+        let inner = tool.inner.clone();
+        tool_map.insert(adk_core::Tool::name(inner.as_ref()).to_string(), inner);
+    }
+
+    let (tx, rx) = tokio::sync::mpsc::channel(32);
+
+    let join_handle = crate::promise::spawn_gil_free(async move {
+        let request = adk_core::LlmRequest {
+            model: model_name,
+            contents: rust_contents,
+            tools: tool_map,
+            config: None,
+        };
+
+        let stream_result = llm.generate_content(request, true).await;
+        let mut stream = match stream_result {
+            Ok(s) => s,
+            Err(e) => {
+                let _ = tx.send(Err(e.into())).await;
+                return;
+            }
+        };
+
+        let invocation_id = uuid::Uuid::new_v4().to_string();
+        while let Some(result) = stream.next().await {
+            let send_result = match result {
+                Ok(llm_response) => {
+                    let mut event = adk_core::Event::new(&invocation_id);
+                    event.author = "model".to_string();
+                    event.llm_response = llm_response;
+                    tx.send(Ok(crate::types::PyEvent::from(event))).await
+                }
+                Err(e) => tx.send(Err(e.into())).await,
+            };
+
+            if send_result.is_err() {
+                break;
+            }
+        }
+    });
+
+    PyEventStream::new_cancellable(rx, join_handle.abort_handle())
+}
+
+/// Google Gemini model wrapper
+#[pyclass(name = "GeminiModel")]
+#[derive(Clone)]
+pub struct PyGeminiModel {
+    pub(crate) inner: Arc<dyn adk_core::Llm>,
+}
+
+#[pymethods]
+impl PyGeminiModel {
+    #[new]
+    #[pyo3(signature = (api_key, model="gemini-2.5-flash"))]
+    fn new(api_key: String, model: &str) -> PyResult<Self> {
+        let gemini = adk_model::GeminiModel::new(&api_key, model)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(gemini),
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    /// Stream generation, yielding `Event`s as they arrive instead of
+    /// blocking for the full response. Events carry `partial=True` chunks
+    /// during token streaming, ending with a `turn_complete=True` event.
+    #[pyo3(signature = (contents, tools=None))]
+    fn generate_stream(
+        &self,
+        contents: Vec<PyContent>,
+        tools: Option<Vec<PyRef<'_, PyFunctionTool>>>,
+    ) -> PyEventStream {
+        generate_stream_for(self.inner.clone(), contents, tools)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("GeminiModel(name='{}')", self.name())
+    }
+}
+
+/// OpenAI model wrapper
+#[pyclass(name = "OpenAIModel")]
+#[derive(Clone)]
+pub struct PyOpenAIModel {
+    pub(crate) inner: Arc<dyn adk_core::Llm>,
+}
+
+#[pymethods]
+impl PyOpenAIModel {
+    #[new]
+    #[pyo3(signature = (api_key, model="gpt-4o"))]
+    fn new(api_key: String, model: &str) -> PyResult<Self> {
+        let config = adk_model::OpenAIConfig::new(&api_key, model);
+        let client = adk_model::OpenAIClient::new(config)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(client),
+        })
+    }
+
+    #[staticmethod]
+    fn compatible(api_key: String, base_url: String, model: String) -> PyResult<Self> {
+        let client = adk_model::OpenAIClient::compatible(&api_key, &base_url, &model)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(client),
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    /// Stream generation, yielding `Event`s as they arrive instead of
+    /// blocking for the full response. Events carry `partial=True` chunks
+    /// during token streaming, ending with a `turn_complete=True` event.
+    #[pyo3(signature = (contents, tools=None))]
+    fn generate_stream(
+        &self,
+        contents: Vec<PyContent>,
+        tools: Option<Vec<PyRef<'_, PyFunctionTool>>>,
+    ) -> PyEventStream {
+        generate_stream_for(self.inner.clone(), contents, tools)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("OpenAIModel(name='{}')", self.name())
+    }
+}
+
+/// Anthropic Claude model wrapper
+#[pyclass(name = "AnthropicModel")]
+#[derive(Clone)]
+pub struct PyAnthropicModel {
+    pub(crate) inner: Arc<dyn adk_core::Llm>,
+}
+
+#[pymethods]
+impl PyAnthropicModel {
+    #[new]
+    #[pyo3(signature = (api_key, model="claude-sonnet-4-20250514"))]
+    fn new(api_key: String, model: &str) -> PyResult<Self> {
+        let config = adk_model::anthropic::AnthropicConfig::new(&api_key, model);
+        let client = adk_model::AnthropicClient::new(config)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(client),
+        })
+    }
+
+    #[staticmethod]
+    fn from_api_key(api_key: String) -> PyResult<Self> {
+        let client = adk_model::AnthropicClient::from_api_key(&api_key)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(client),
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    /// Stream generation, yielding `Event`s as they arrive instead of
+    /// blocking for the full response. Events carry `partial=True` chunks
+    /// during token streaming, ending with a `turn_complete=True` event.
+    #[pyo3(signature = (contents, tools=None))]
+    fn generate_stream(
+        &self,
+        contents: Vec<PyContent>,
+        tools: Option<Vec<PyRef<'_, PyFunctionTool>>>,
+    ) -> PyEventStream {
+        generate_stream_for(self.inner.clone(), contents, tools)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("AnthropicModel(name='{}')", self.name())
+    }
+}
+
+/// DeepSeek model wrapper
+#[pyclass(name = "DeepSeekModel")]
+#[derive(Clone)]
+pub struct PyDeepSeekModel {
+    pub(crate) inner: Arc<dyn adk_core::Llm>,
+}
+
+#[pymethods]
+impl PyDeepSeekModel {
+    #[new]
+    fn new(api_key: String, model: String) -> PyResult<Self> {
+        let config = adk_model::DeepSeekConfig::new(&api_key, &model);
+        let client = adk_model::DeepSeekClient::new(config)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(client),
+        })
+    }
+
+    #[staticmethod]
+    fn chat(api_key: String) -> PyResult<Self> {
+        let client = adk_model::DeepSeekClient::chat(&api_key)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(client),
+        })
+    }
+
+    #[staticmethod]
+    fn reasoner(api_key: String) -> PyResult<Self> {
+        let client = adk_model::DeepSeekClient::reasoner(&api_key)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(client),
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    /// Stream generation, yielding `Event`s as they arrive instead of
+    /// blocking for the full response. Events carry `partial=True` chunks
+    /// during token streaming, ending with a `turn_complete=True` event.
+    #[pyo3(signature = (contents, tools=None))]
+    fn generate_stream(
+        &self,
+        contents: Vec<PyContent>,
+        tools: Option<Vec<PyRef<'_, PyFunctionTool>>>,
+    ) -> PyEventStream {
+        generate_stream_for(self.inner.clone(), contents, tools)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("DeepSeekModel(name='{}')", self.name())
+    }
+}
+
+/// Groq model wrapper (fast inference)
+#[pyclass(name = "GroqModel")]
+#[derive(Clone)]
+pub struct PyGroqModel {
+    pub(crate) inner: Arc<dyn adk_core::Llm>,
+}
+
+#[pymethods]
+impl PyGroqModel {
+    #[new]
+    fn new(api_key: String, model: String) -> PyResult<Self> {
+        let config = adk_model::GroqConfig::new(&api_key, &model);
+        let client = adk_model::GroqClient::new(config)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(client),
+        })
+    }
+
+    #[staticmethod]
+    fn llama70b(api_key: String) -> PyResult<Self> {
+        let client = adk_model::GroqClient::llama70b(&api_key)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(client),
+        })
+    }
+
+    #[staticmethod]
+    fn llama8b(api_key: String) -> PyResult<Self> {
+        let client = adk_model::GroqClient::llama8b(&api_key)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(client),
+        })
+    }
+
+    #[staticmethod]
+    fn mixtral(api_key: String) -> PyResult<Self> {
+        let client = adk_model::GroqClient::mixtral(&api_key)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(client),
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    /// Stream generation, yielding `Event`s as they arrive instead of
+    /// blocking for the full response. Events carry `partial=True` chunks
+    /// during token streaming, ending with a `turn_complete=True` event.
+    #[pyo3(signature = (contents, tools=None))]
+    fn generate_stream(
+        &self,
+        contents: Vec<PyContent>,
+        tools: Option<Vec<PyRef<'_, PyFunctionTool>>>,
+    ) -> PyEventStream {
+        generate_stream_for(self.inner.clone(), contents, tools)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("GroqModel(name='{}')", self.name())
+    }
+}
+
+/// Ollama model wrapper (local inference)
+#[pyclass(name = "OllamaModel")]
+#[derive(Clone)]
+pub struct PyOllamaModel {
+    pub(crate) inner: Arc<dyn adk_core::Llm>,
+}
+
+#[pymethods]
+impl PyOllamaModel {
+    #[new]
+    fn new(model: String) -> PyResult<Self> {
+        let ollama = adk_model::OllamaModel::from_model(&model)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(ollama),
+        })
+    }
+
+    #[staticmethod]
+    fn with_host(host: String, model: String) -> PyResult<Self> {
+        let config = adk_model::OllamaConfig::with_host(&host, &model);
+        let ollama = adk_model::OllamaModel::new(config)
+            .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?;
+        Ok(Self {
+            inner: Arc::new(ollama),
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    /// Stream generation, yielding `Event`s as they arrive instead of
+    /// blocking for the full response. Events carry `partial=True` chunks
+    /// during token streaming, ending with a `turn_complete=True` event.
+    #[pyo3(signature = (contents, tools=None))]
+    fn generate_stream(
+        &self,
+        contents: Vec<PyContent>,
+        tools: Option<Vec<PyRef<'_, PyFunctionTool>>>,
+    ) -> PyEventStream {
+        generate_stream_for(self.inner.clone(), contents, tools)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("OllamaModel(name='{}')", self.name())
+    }
+}
+
+/// Mock LLM for testing
+#[pyclass(name = "MockLlm")]
+#[derive(Clone)]
+pub struct PyMockLlm {
+    pub(crate) inner: Arc<dyn adk_core::Llm>,
+}
+
+#[pymethods]
+impl PyMockLlm {
+    #[new]
+    #[pyo3(signature = (name, response_text="Mock response"))]
+    fn new(name: String, response_text: &str) -> Self {
+        let response =
+            adk_core::LlmResponse::new(adk_core::Content::new("model").with_text(response_text));
+        let mock = adk_model::MockLlm::new(&name).with_response(response);
+        Self {
+            inner: Arc::new(mock),
+        }
+    }
+
+    /// Start building a scripted mock that replays an ordered sequence of
+    /// responses across successive `generate_content` calls, reproducing
+    /// multi-step tool-calling agent loops offline.
+    #[staticmethod]
+    fn scripted(name: String) -> PyMockLlmBuilder {
+        PyMockLlmBuilder {
+            name,
+            queue: VecDeque::new(),
+            on_function_response: Vec::new(),
+        }
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    /// Stream generation, yielding `Event`s as they arrive instead of
+    /// blocking for the full response. Events carry `partial=True` chunks
+    /// during token streaming, ending with a `turn_complete=True` event.
+    #[pyo3(signature = (contents, tools=None))]
+    fn generate_stream(
+        &self,
+        contents: Vec<PyContent>,
+        tools: Option<Vec<PyRef<'_, PyFunctionTool>>>,
+    ) -> PyEventStream {
+        generate_stream_for(self.inner.clone(), contents, tools)
+    }
+
+    fn __repr__(&self) -> String {
+        format!("MockLlm(name='{}')", self.name())
+    }
+}
+
+/// One queued step in a `ScriptedMockLlm`'s response sequence.
+#[derive(Clone)]
+enum ScriptStep {
+    Text(String),
+    FunctionCall {
+        name: String,
+        args: serde_json::Value,
+    },
+    FunctionResponseEcho,
+}
+
+/// A mock LLM that replays a scripted, ordered sequence of responses,
+/// consuming one queued step per `generate_content` call. Also supports
+/// predicate responses keyed on an incoming `FunctionResponse`, so a test
+/// can drive the request -> tool call -> tool response -> final answer
+/// pattern an agent runtime exercises, entirely offline.
+struct ScriptedMockLlm {
+    name: String,
+    queue: Mutex<VecDeque<ScriptStep>>,
+    on_function_response: Vec<(String, String)>,
+}
+
+impl ScriptedMockLlm {
+    fn text_response(text: &str) -> adk_core::LlmResponse {
+        adk_core::LlmResponse::new(adk_core::Content::new("model").with_text(text))
+    }
+
+    /// If the most recent content carries a `FunctionResponse` for a
+    /// registered tool name, return the predicate's configured next text.
+    fn match_function_response(&self, request: &adk_core::LlmRequest) -> Option<String> {
+        let last = request.contents.last()?;
+        last.parts.iter().find_map(|part| {
+            if let adk_core::Part::FunctionResponse {
+                function_response, ..
+            } = part
+            {
+                self.on_function_response
+                    .iter()
+                    .find(|(tool_name, _)| tool_name == &function_response.name)
+                    .map(|(_, next_text)| next_text.clone())
+            } else {
+                None
+            }
+        })
+    }
+
+    /// The raw `FunctionResponse` value of the most recent content, if any,
+    /// stringified for `then_function_response_echo`.
+    fn last_function_response_text(request: &adk_core::LlmRequest) -> String {
+        request
+            .contents
+            .last()
+            .and_then(|content| {
+                content.parts.iter().find_map(|part| {
+                    if let adk_core::Part::FunctionResponse {
+                        function_response, ..
+                    } = part
+                    {
+                        Some(function_response.response.to_string())
+                    } else {
+                        None
+                    }
+                })
+            })
+            .unwrap_or_default()
+    }
+}
+
+#[async_trait]
+impl adk_core::Llm for ScriptedMockLlm {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    async fn generate_content(
+        &self,
+        request: adk_core::LlmRequest,
+        _stream: bool,
+    ) -> adk_core::Result<Pin<Box<dyn Stream<Item = adk_core::Result<adk_core::LlmResponse>> + Send>>>
+    {
+        if let Some(next_text) = self.match_function_response(&request) {
+            let response = Self::text_response(&next_text);
+            return Ok(Box::pin(futures::stream::once(async move { Ok(response) })));
+        }
+
+        let step = self.queue.lock().unwrap().pop_front();
+        let response = match step {
+            Some(ScriptStep::Text(text)) => Self::text_response(&text),
+            Some(ScriptStep::FunctionCall { name, args }) => {
+                adk_core::LlmResponse::new(adk_core::Content {
+                    role: "model".to_string(),
+                    parts: vec![adk_core::Part::FunctionCall {
+                        name,
+                        args,
+                        id: None,
+                    }],
+                })
+            }
+            Some(ScriptStep::FunctionResponseEcho) => {
+                Self::text_response(&Self::last_function_response_text(&request))
+            }
+            None => Self::text_response(""),
+        };
+
+        Ok(Box::pin(futures::stream::once(async move { Ok(response) })))
+    }
+}
+
+/// Builder for a `ScriptedMockLlm`, created via `MockLlm.scripted(name)`.
+#[pyclass(name = "MockLlmBuilder")]
+pub struct PyMockLlmBuilder {
+    name: String,
+    queue: VecDeque<ScriptStep>,
+    on_function_response: Vec<(String, String)>,
+}
+
+#[pymethods]
+impl PyMockLlmBuilder {
+    /// Queue a plain text response.
+    fn then_text(mut slf: PyRefMut<'_, Self>, text: String) -> PyRefMut<'_, Self> {
+        slf.queue.push_back(ScriptStep::Text(text));
+        slf
+    }
+
+    /// Queue a function-call response.
+    fn then_function_call<'a>(
+        mut slf: PyRefMut<'a, Self>,
+        name: String,
+        args: &Bound<'a, PyAny>,
+    ) -> PyResult<PyRefMut<'a, Self>> {
+        let args_json: serde_json::Value = pythonize::depythonize(args)
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        slf.queue.push_back(ScriptStep::FunctionCall {
+            name,
+            args: args_json,
+        });
+        Ok(slf)
+    }
+
+    /// Queue a response that echoes the most recently received
+    /// `FunctionResponse` value back as text - useful for asserting a
+    /// tool's return value round-tripped correctly.
+    fn then_function_response_echo(mut slf: PyRefMut<'_, Self>) -> PyRefMut<'_, Self> {
+        slf.queue.push_back(ScriptStep::FunctionResponseEcho);
+        slf
+    }
+
+    /// Register a predicate response: whenever a request's most recent
+    /// content carries a `FunctionResponse` for `tool_name`, return
+    /// `next_text` instead of consuming the plain queue. This reproduces
+    /// the request -> tool -> request -> final pattern an agent runtime
+    /// drives, without needing to know exactly where in the queue the
+    /// tool call will land.
+    fn on_function_response(
+        mut slf: PyRefMut<'_, Self>,
+        tool_name: String,
+        next_text: String,
+    ) -> PyRefMut<'_, Self> {
+        slf.on_function_response.push((tool_name, next_text));
+        slf
+    }
+
+    /// Finish building the scripted mock.
+    fn build(&self) -> PyMockLlm {
+        let llm = ScriptedMockLlm {
+            name: self.name.clone(),
+            queue: Mutex::new(self.queue.clone()),
+            on_function_response: self.on_function_response.clone(),
+        };
+        PyMockLlm {
+            inner: Arc::new(llm),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "MockLlmBuilder(name='{}', queued={})",
+            self.name,
+            self.queue.len()
+        )
+    }
+}
+
+/// Current version of the flat provider spec consumed by [`PyModel::from_spec`].
+const SPEC_VERSION: u32 = 1;
+
+fn default_spec_version() -> u32 {
+    SPEC_VERSION
+}
+
+/// A raw, versioned model spec as handed in from Python, e.g.:
+/// `{"version": 1, "provider": "anthropic", "name": "claude-...", "max_tokens": 200000, "params": {...}}`
+#[derive(Debug, Clone, Deserialize)]
+struct ModelSpec {
+    // Accepted under either key so older flat configs (which only ever
+    // wrote `version`) keep parsing as the schema evolves under the
+    // `config_version` name used by `PyModel::from_config`.
+    #[serde(default = "default_spec_version", alias = "config_version")]
+    version: u32,
+    provider: String,
+    name: String,
+    #[serde(default)]
+    api_key: String,
+    base_url: Option<String>,
+    max_tokens: Option<u32>,
+    #[serde(default)]
+    params: serde_json::Value,
+}
+
+/// Generic provider model built from a flat, versioned JSON spec.
+///
+/// Unlike the per-provider wrappers above, `Model.from_spec` dispatches on a
+/// `provider` string and forwards the spec's `params` straight through to the
+/// underlying `adk_model` client config as raw JSON, so newly released models
+/// can be adopted without waiting for a new `PyXxxModel` struct. The
+/// `gemini` provider is built from `api_key` and `name` only; its `params`
+/// are not applied.
+#[pyclass(name = "Model")]
+#[derive(Clone)]
+pub struct PyModel {
+    pub(crate) inner: Arc<dyn adk_core::Llm>,
+}
+
+#[pymethods]
+impl PyModel {
+    /// Build a model from a single flat spec dict.
+    #[staticmethod]
+    fn from_spec(spec: &Bound<'_, PyDict>) -> PyResult<Self> {
+        let value: serde_json::Value = pythonize::depythonize(spec.as_any())
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+        let spec: ModelSpec = serde_json::from_value(value).map_err(|e| {
+            pyo3::exceptions::PyValueError::new_err(format!("invalid model spec: {e}"))
+        })?;
+        Self::from_model_spec(spec)
+    }
+
+    /// Build a list of models from a list of flat spec dicts.
+    #[staticmethod]
+    fn from_specs(specs: Vec<Bound<'_, PyDict>>) -> PyResult<Vec<Self>> {
+        specs.iter().map(Self::from_spec).collect()
+    }
+
+    /// Build a model from a flat config dict, e.g.
+    /// `{"provider": "anthropic", "name": "...", "api_key": "...",
+    /// "max_tokens": 200000, "base_url": None}`.
+    ///
+    /// This is the same spec format as `from_spec` - `config_version` is
+    /// simply the more descriptive name under which the version key is
+    /// also accepted, so a whole model registry can be declared as plain
+    /// data without picking a provider-specific wrapper class.
+    #[staticmethod]
+    fn from_config(config: &Bound<'_, PyDict>) -> PyResult<Self> {
+        Self::from_spec(config)
+    }
+
+    /// Build a name -> model registry from a list of flat config dicts.
+    ///
+    /// Each dict's `"name"` becomes its key in the returned mapping.
+    #[staticmethod]
+    fn registry_from_configs(configs: Vec<Bound<'_, PyDict>>) -> PyResult<HashMap<String, Self>> {
+        let mut registry = HashMap::with_capacity(configs.len());
+        for config in &configs {
+            let model = Self::from_config(config)?;
+            registry.insert(model.name(), model);
+        }
+        Ok(registry)
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Model(name='{}')", self.name())
+    }
+}
+
+impl PyModel {
+    fn from_model_spec(spec: ModelSpec) -> PyResult<Self> {
+        if spec.version != SPEC_VERSION {
+            return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                "unsupported model spec version {} (expected {})",
+                spec.version, SPEC_VERSION
+            )));
+        }
+
+        let llm: Arc<dyn adk_core::Llm> = match spec.provider.as_str() {
+            "gemini" => Arc::new(
+                adk_model::GeminiModel::new(&spec.api_key, &spec.name)
+                    .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?,
+            ),
+            "openai" => {
+                let mut config = adk_model::OpenAIConfig::new(&spec.api_key, &spec.name);
+                if let Some(base_url) = &spec.base_url {
+                    config = config.with_base_url(base_url);
+                }
+                config = config.with_raw_params(spec.params.clone());
+                Arc::new(
+                    adk_model::OpenAIClient::new(config)
+                        .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?,
+                )
+            }
+            "anthropic" => {
+                let mut config =
+                    adk_model::anthropic::AnthropicConfig::new(&spec.api_key, &spec.name);
+                config = config.with_raw_params(spec.params.clone());
+                Arc::new(
+                    adk_model::AnthropicClient::new(config)
+                        .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?,
+                )
+            }
+            "deepseek" => {
+                let mut config = adk_model::DeepSeekConfig::new(&spec.api_key, &spec.name);
+                config = config.with_raw_params(spec.params.clone());
+                Arc::new(
+                    adk_model::DeepSeekClient::new(config)
+                        .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?,
+                )
+            }
+            "groq" => {
+                let mut config = adk_model::GroqConfig::new(&spec.api_key, &spec.name);
+                config = config.with_raw_params(spec.params.clone());
+                Arc::new(
+                    adk_model::GroqClient::new(config)
+                        .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?,
+                )
+            }
+            "ollama" => {
+                let mut config = adk_model::OllamaConfig::with_host(
+                    spec.base_url.as_deref().unwrap_or("http://localhost:11434"),
+                    &spec.name,
+                );
+                config = config.with_raw_params(spec.params.clone());
+                Arc::new(
+                    adk_model::OllamaModel::new(config)
+                        .map_err(|e| crate::error::ModelError::new_err(e.to_string()))?,
+                )
+            }
+            other => {
+                return Err(pyo3::exceptions::PyValueError::new_err(format!(
+                    "unknown model provider: '{other}'"
+                )));
+            }
+        };
+
+        let _ = spec.max_tokens; // not applied to any client config; only `params` reaches the client
+
+        Ok(Self { inner: llm })
+    }
+}
+
+/// Helper to extract an Llm trait object from any model type
+pub fn extract_llm(obj: &Bound<'_, PyAny>) -> PyResult<Arc<dyn adk_core::Llm>> {
+    if let Ok(model) = obj.extract::<PyRef<'_, PyGeminiModel>>() {
+        return Ok(model.inner.clone());
+    }
+    if let Ok(model) = obj.extract::<PyRef<'_, PyOpenAIModel>>() {
+        return Ok(model.inner.clone());
+    }
+    if let Ok(model) = obj.extract::<PyRef<'_, PyAnthropicModel>>() {
+        return Ok(model.inner.clone());
+    }
+    if let Ok(model) = obj.extract::<PyRef<'_, PyDeepSeekModel>>() {
+        return Ok(model.inner.clone());
+    }
+    if let Ok(model) = obj.extract::<PyRef<'_, PyGroqModel>>() {
+        return Ok(model.inner.clone());
+    }
+    if let Ok(model) = obj.extract::<PyRef<'_, PyOllamaModel>>() {
+        return Ok(model.inner.clone());
+    }
+    if let Ok(model) = obj.extract::<PyRef<'_, PyMockLlm>>() {
+        return Ok(model.inner.clone());
+    }
+    if let Ok(model) = obj.extract::<PyRef<'_, PyModel>>() {
+        return Ok(model.inner.clone());
+    }
+    Err(pyo3::exceptions::PyTypeError::new_err(
+        "Expected a model type (GeminiModel, OpenAIModel, AnthropicModel, DeepSeekModel, GroqModel, OllamaModel, Model, or MockLlm)",
+    ))
+}
@@ -0,0 +1,171 @@
+//! A lightweight Python-facing handle for Rust-side async work.
+//!
+//! `Driver` is the entry point for spawning background Rust work (e.g. a
+//! tool prefetch) onto the Tokio runtime from a Python callback; the
+//! `Promise` it returns can be polled (`is_done()`), blocked on (`wait()`),
+//! or `await`ed directly. Both `wait()` and `__await__` release the GIL
+//! (`py.allow_threads`, `future_into_py`) while the work is in flight, so
+//! Rust doesn't pin the GIL for the whole duration and other Python async
+//! tasks keep making progress on the interpreter's event loop.
+//!
+//! **Invariant**: never call `.block_on(...)` on the shared runtime while
+//! the GIL is held. A task running on that runtime may itself need the GIL
+//! (e.g. to call back into a Python callback or tool handler); if the
+//! calling thread is blocked on `block_on` without releasing the GIL first,
+//! the two deadlock. `spawn_gil_free` below is the shared, GIL-safe way to
+//! hand work to the runtime from a synchronous pymethod.
+
+use pyo3::prelude::*;
+use std::sync::Mutex;
+use tokio::task::JoinHandle;
+
+/// The error side carries `AdkPyError` rather than a bare `String` so a
+/// `Promise` born from a typed `AdkError` (see `PyRunner::run_blocking`)
+/// surfaces the matching exception subclass instead of a generic
+/// `RuntimeError` once `wait()`/`__await__` reconstructs the `PyErr`.
+type PromiseResult = Result<Py<PyAny>, crate::error::AdkPyError>;
+
+/// Spawn `fut` onto the shared Tokio runtime without holding the GIL while
+/// the scheduler enqueues it, per the invariant above. Cheap insurance: it
+/// turns "must remember to release the GIL before touching the runtime"
+/// into a single call site every detached-spawn caller goes through.
+pub(crate) fn spawn_gil_free<F>(fut: F) -> JoinHandle<F::Output>
+where
+    F: std::future::Future + Send + 'static,
+    F::Output: Send + 'static,
+{
+    Python::with_gil(|py| py.allow_threads(|| pyo3_async_runtimes::tokio::get_runtime().spawn(fut)))
+}
+
+/// A handle to Rust work spawned via `Driver.spawn()`.
+#[pyclass(name = "Promise")]
+pub struct PyPromise {
+    handle: Mutex<Option<JoinHandle<PromiseResult>>>,
+}
+
+impl PyPromise {
+    pub(crate) fn spawn<F>(fut: F) -> Self
+    where
+        F: std::future::Future<Output = PromiseResult> + Send + 'static,
+    {
+        let handle = spawn_gil_free(fut);
+        Self {
+            handle: Mutex::new(Some(handle)),
+        }
+    }
+
+    fn take_handle(&self) -> PyResult<JoinHandle<PromiseResult>> {
+        self.handle
+            .lock()
+            .unwrap()
+            .take()
+            .ok_or_else(|| pyo3::exceptions::PyRuntimeError::new_err("Promise already awaited"))
+    }
+
+    /// An `AbortHandle` for the still-running task, or `None` if it was
+    /// already consumed by `wait()`/`__await__`. Used by `Driver` to track
+    /// and later `stop()` everything it spawned.
+    pub(crate) fn abort_handle(&self) -> Option<tokio::task::AbortHandle> {
+        self.handle
+            .lock()
+            .unwrap()
+            .as_ref()
+            .map(JoinHandle::abort_handle)
+    }
+}
+
+#[pymethods]
+impl PyPromise {
+    /// True once the spawned work has finished (or failed), without
+    /// consuming the Promise or blocking to find out.
+    fn is_done(&self) -> bool {
+        match self.handle.lock().unwrap().as_ref() {
+            Some(handle) => handle.is_finished(),
+            None => true, // already consumed by wait() / __await__
+        }
+    }
+
+    /// Block the calling thread until the work finishes, releasing the GIL
+    /// for the duration so other Python threads can make progress.
+    fn wait(&self, py: Python<'_>) -> PyResult<Py<PyAny>> {
+        let handle = self.take_handle()?;
+
+        py.allow_threads(|| pyo3_async_runtimes::tokio::get_runtime().block_on(handle))
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+            .map_err(PyErr::from)
+    }
+
+    /// Await the Promise from an `async def`, driving it on the
+    /// interpreter's own event loop instead of blocking a thread.
+    fn __await__<'py>(slf: PyRef<'py, Self>, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let handle = slf.take_handle()?;
+
+        let coro = pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let result = handle
+                .await
+                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?
+                .map_err(PyErr::from)?;
+            Ok(result)
+        })?;
+        coro.call_method0("__await__")
+    }
+}
+
+/// Entry point for spawning background Rust work from a Python callback.
+/// Thin: it exists to give Python an explicit handle on "run this on the
+/// Tokio runtime" rather than reaching for the runtime directly. Tracks the
+/// `AbortHandle` of every `Promise` it has spawned so `stop()` can cancel
+/// them as a group - a single place to tear down runaway background work
+/// instead of leaking spawned tasks.
+#[pyclass(name = "Driver")]
+#[derive(Default)]
+pub struct PyDriver {
+    handles: Mutex<Vec<tokio::task::AbortHandle>>,
+}
+
+impl PyDriver {
+    fn track(&self, handle: Option<tokio::task::AbortHandle>) {
+        let Some(handle) = handle else { return };
+        let mut handles = self.handles.lock().unwrap();
+        handles.retain(|h| !h.is_finished());
+        handles.push(handle);
+    }
+}
+
+#[pymethods]
+impl PyDriver {
+    #[new]
+    fn new() -> Self {
+        Self::default()
+    }
+
+    /// Spawn `coro` (a Python awaitable) on the Tokio runtime and return a
+    /// `Promise` handle immediately, without blocking on it or holding the
+    /// GIL for the duration of the work.
+    fn spawn(&self, coro: Py<PyAny>) -> PyResult<PyPromise> {
+        let future =
+            Python::with_gil(|py| pyo3_async_runtimes::tokio::into_future(coro.bind(py).clone()))?;
+
+        let promise = PyPromise::spawn(async move {
+            future
+                .await
+                .map_err(|e| crate::error::AdkPyError::Python(e.to_string()))
+        });
+        self.track(promise.abort_handle());
+        Ok(promise)
+    }
+
+    /// Abort every task spawned via this `Driver` that's still running.
+    /// Tasks that already completed are unaffected; a `Promise.wait()` or
+    /// `await` on an aborted task raises once the abort lands.
+    fn stop(&self) {
+        let handles = self.handles.lock().unwrap();
+        for handle in handles.iter() {
+            handle.abort();
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        "Driver()".to_string()
+    }
+}
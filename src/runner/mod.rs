@@ -1,322 +1,598 @@
-//! Runner bindings for executing agents
-//!
-//! This module provides Python bindings for agent execution:
-//! - `Runner` - Execute agents with full configuration
-//! - `EventStream` - Async iterator for streaming events
-//! - `run_agent()` - Convenience function for simple execution
-
-use adk_session::SessionService;
-use futures::StreamExt;
-use pyo3::prelude::*;
-use std::sync::Arc;
-use tokio::sync::Mutex;
-
-use crate::agent::PyLlmAgent;
-use crate::session::{PyInMemorySessionService, PyRunConfig};
-use crate::types::PyEvent;
-
-/// Async iterator for streaming events from agent execution.
-///
-/// Use with `async for`:
-/// ```python
-/// async for event in runner.run_stream(user_id, session_id, message):
-///     print(event.get_text())
-/// ```
-#[pyclass(name = "EventStream")]
-pub struct PyEventStream {
-    receiver: Arc<Mutex<tokio::sync::mpsc::Receiver<Result<PyEvent, String>>>>,
-}
-
-#[pymethods]
-impl PyEventStream {
-    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
-        slf
-    }
-
-    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
-        let receiver = self.receiver.clone();
-
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let mut rx = receiver.lock().await;
-            match rx.recv().await {
-                Some(Ok(event)) => Ok(Some(event)),
-                Some(Err(e)) => Err(pyo3::exceptions::PyRuntimeError::new_err(e)),
-                None => Ok(None), // Stream exhausted - signals StopAsyncIteration
-            }
-        })
-    }
-}
-
-/// Runner for executing agents
-#[pyclass(name = "Runner")]
-pub struct PyRunner {
-    app_name: String,
-    agent: Arc<dyn adk_core::Agent>,
-    session_service: Arc<dyn adk_session::SessionService>,
-    run_config: Option<adk_core::RunConfig>,
-}
-
-#[pymethods]
-impl PyRunner {
-    #[new]
-    #[pyo3(signature = (app_name, agent, session_service, run_config=None))]
-    fn new(
-        app_name: String,
-        agent: &PyLlmAgent,
-        session_service: &PyInMemorySessionService,
-        run_config: Option<&PyRunConfig>,
-    ) -> Self {
-        Self {
-            app_name,
-            agent: agent.inner.clone(),
-            session_service: session_service.inner.clone(),
-            run_config: run_config.map(|c| c.clone().into()),
-        }
-    }
-
-    /// Run the agent with the given user message, returning all events
-    fn run<'py>(
-        &self,
-        py: Python<'py>,
-        user_id: String,
-        session_id: String,
-        message: String,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        let agent = self.agent.clone();
-        let session_service = self.session_service.clone();
-        let app_name = self.app_name.clone();
-        let run_config = self.run_config.clone();
-
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let user_content = adk_core::Content::new("user").with_text(&message);
-
-            let config = adk_runner::RunnerConfig {
-                app_name,
-                agent,
-                session_service,
-                artifact_service: None,
-                memory_service: None,
-                run_config,
-            };
-
-            let runner = adk_runner::Runner::new(config)
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-
-            let stream_result = runner.run(user_id, session_id, user_content).await;
-            let mut stream = stream_result
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-
-            let mut events = Vec::new();
-
-            while let Some(result) = stream.next().await {
-                match result {
-                    Ok(event) => events.push(PyEvent::from(event)),
-                    Err(e) => return Err(pyo3::exceptions::PyRuntimeError::new_err(e.to_string())),
-                }
-            }
-
-            Ok(events)
-        })
-    }
-
-    /// Run the agent and return just the final response text
-    fn run_simple<'py>(
-        &self,
-        py: Python<'py>,
-        user_id: String,
-        session_id: String,
-        message: String,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        let agent = self.agent.clone();
-        let session_service = self.session_service.clone();
-        let app_name = self.app_name.clone();
-        let run_config = self.run_config.clone();
-
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let user_content = adk_core::Content::new("user").with_text(&message);
-
-            let config = adk_runner::RunnerConfig {
-                app_name,
-                agent,
-                session_service,
-                artifact_service: None,
-                memory_service: None,
-                run_config,
-            };
-
-            let runner = adk_runner::Runner::new(config)
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-
-            let stream_result = runner.run(user_id, session_id, user_content).await;
-            let mut stream = stream_result
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-
-            let mut final_text = String::new();
-
-            while let Some(result) = stream.next().await {
-                match result {
-                    Ok(event) => {
-                        if event.is_final_response() {
-                            if let Some(content) = event.content() {
-                                for part in content.parts.iter() {
-                                    if let Some(text) = part.text() {
-                                        final_text.push_str(text);
-                                    }
-                                }
-                            }
-                        }
-                    }
-                    Err(e) => return Err(pyo3::exceptions::PyRuntimeError::new_err(e.to_string())),
-                }
-            }
-
-            Ok(final_text)
-        })
-    }
-
-    /// Run the agent with streaming - returns an async iterator of events.
-    ///
-    /// Use with `async for`:
-    /// ```python
-    /// async for event in runner.run_stream(user_id, session_id, message):
-    ///     if text := event.get_text():
-    ///         print(text, end="", flush=True)
-    /// ```
-    fn run_stream(
-        &self,
-        _py: Python<'_>,
-        user_id: String,
-        session_id: String,
-        message: String,
-    ) -> PyResult<PyEventStream> {
-        let agent = self.agent.clone();
-        let session_service = self.session_service.clone();
-        let app_name = self.app_name.clone();
-        let run_config = self.run_config.clone();
-
-        // Create a channel for sending events
-        let (tx, rx) = tokio::sync::mpsc::channel(32);
-
-        // Spawn a task that reads from the Rust stream and sends to the channel
-        pyo3_async_runtimes::tokio::get_runtime().spawn(async move {
-            let user_content = adk_core::Content::new("user").with_text(&message);
-
-            let config = adk_runner::RunnerConfig {
-                app_name,
-                agent,
-                session_service,
-                artifact_service: None,
-                memory_service: None,
-                run_config,
-            };
-
-            let runner = match adk_runner::Runner::new(config) {
-                Ok(r) => r,
-                Err(e) => {
-                    let _ = tx.send(Err(e.to_string())).await;
-                    return;
-                }
-            };
-
-            let stream_result = runner.run(user_id, session_id, user_content).await;
-            let mut stream = match stream_result {
-                Ok(s) => s,
-                Err(e) => {
-                    let _ = tx.send(Err(e.to_string())).await;
-                    return;
-                }
-            };
-
-            while let Some(result) = stream.next().await {
-                let send_result = match result {
-                    Ok(event) => tx.send(Ok(PyEvent::from(event))).await,
-                    Err(e) => tx.send(Err(e.to_string())).await,
-                };
-
-                if send_result.is_err() {
-                    // Receiver dropped, stop sending
-                    break;
-                }
-            }
-            // Channel closes when tx is dropped
-        });
-
-        Ok(PyEventStream {
-            receiver: Arc::new(Mutex::new(rx)),
-        })
-    }
-
-    fn __repr__(&self) -> String {
-        format!("Runner(app_name='{}')", self.app_name)
-    }
-}
-
-/// Simple function to run an agent once
-#[pyfunction]
-#[pyo3(signature = (agent, message, user_id="default_user", session_id="default_session", app_name="adk_app"))]
-pub fn run_agent<'py>(
-    py: Python<'py>,
-    agent: &PyLlmAgent,
-    message: String,
-    user_id: &str,
-    session_id: &str,
-    app_name: &str,
-) -> PyResult<Bound<'py, PyAny>> {
-    let agent = agent.inner.clone();
-    let user_id = user_id.to_string();
-    let session_id = session_id.to_string();
-    let app_name = app_name.to_string();
-
-    pyo3_async_runtimes::tokio::future_into_py(py, async move {
-        let user_content = adk_core::Content::new("user").with_text(&message);
-        let session_service = Arc::new(adk_session::InMemorySessionService::new());
-
-        // Create session first (required by runner)
-        session_service
-            .create(adk_session::CreateRequest {
-                app_name: app_name.clone(),
-                user_id: user_id.clone(),
-                session_id: Some(session_id.clone()),
-                state: Default::default(),
-            })
-            .await
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-
-        let config = adk_runner::RunnerConfig {
-            app_name,
-            agent,
-            session_service,
-            artifact_service: None,
-            memory_service: None,
-            run_config: None,
-        };
-
-        let runner = adk_runner::Runner::new(config)
-            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-
-        let stream_result = runner.run(user_id, session_id, user_content).await;
-        let mut stream =
-            stream_result.map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-
-        let mut final_text = String::new();
-
-        while let Some(result) = stream.next().await {
-            match result {
-                Ok(event) => {
-                    if event.is_final_response() {
-                        if let Some(content) = event.content() {
-                            for part in content.parts.iter() {
-                                if let Some(text) = part.text() {
-                                    final_text.push_str(text);
-                                }
-                            }
-                        }
-                    }
-                }
-                Err(e) => return Err(pyo3::exceptions::PyRuntimeError::new_err(e.to_string())),
-            }
-        }
-
-        Ok(final_text)
-    })
-}
+//! Runner bindings for executing agents
+//!
+//! This module provides Python bindings for agent execution:
+//! - `Runner` - Execute agents with full configuration
+//! - `EventStream` - Async iterator for streaming events
+//! - `run_agent()` - Convenience function for simple execution
+
+use adk_session::SessionService;
+use chrono::{DateTime, Utc};
+use futures::StreamExt;
+use pyo3::prelude::*;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+
+use crate::agent::PyLlmAgent;
+use crate::promise::PyPromise;
+use crate::session::{extract_session_service, PyRunConfig};
+use crate::types::PyEvent;
+
+/// Async iterator for streaming events from agent execution.
+///
+/// Use with `async for`:
+/// ```python
+/// async for event in runner.run_stream(user_id, session_id, message):
+///     print(event.get_text())
+/// ```
+///
+/// Also usable as an async context manager, which cancels the background
+/// task feeding the stream on exit (whether or not the loop ran to
+/// completion), so a `break` out of `async for` doesn't leave a runaway
+/// agent run going in the background:
+/// ```python
+/// async with runner.run_stream(user_id, session_id, message) as stream:
+///     async for event in stream:
+///         ...
+/// ```
+#[pyclass(name = "EventStream")]
+pub struct PyEventStream {
+    receiver: Arc<Mutex<tokio::sync::mpsc::Receiver<Result<PyEvent, crate::error::AdkPyError>>>>,
+    abort_handle: Option<tokio::task::AbortHandle>,
+    resumption_token: Arc<std::sync::Mutex<Option<DateTime<Utc>>>>,
+}
+
+impl PyEventStream {
+    /// Wrap a raw event receiver with no associated background task to
+    /// cancel (e.g. one fed synchronously, not via a spawned producer).
+    pub(crate) fn new(
+        receiver: tokio::sync::mpsc::Receiver<Result<PyEvent, crate::error::AdkPyError>>,
+    ) -> Self {
+        Self {
+            receiver: Arc::new(Mutex::new(receiver)),
+            abort_handle: None,
+            resumption_token: Arc::new(std::sync::Mutex::new(None)),
+        }
+    }
+
+    /// Wrap a raw event receiver fed by a spawned producer task, allowing
+    /// `cancel()`/`__aexit__` to abort that task directly.
+    pub(crate) fn new_cancellable(
+        receiver: tokio::sync::mpsc::Receiver<Result<PyEvent, crate::error::AdkPyError>>,
+        abort_handle: tokio::task::AbortHandle,
+    ) -> Self {
+        Self {
+            receiver: Arc::new(Mutex::new(receiver)),
+            abort_handle: Some(abort_handle),
+            resumption_token: Arc::new(std::sync::Mutex::new(None)),
+        }
+    }
+
+    /// Like `new_cancellable`, additionally sharing a resumption-token cell
+    /// with the producer task so `resumption_token()` reflects the last
+    /// event actually delivered. Used by `Runner.run_stream()` to support
+    /// `RunConfig(resumable=True)`.
+    pub(crate) fn new_resumable(
+        receiver: tokio::sync::mpsc::Receiver<Result<PyEvent, crate::error::AdkPyError>>,
+        abort_handle: tokio::task::AbortHandle,
+        resumption_token: Arc<std::sync::Mutex<Option<DateTime<Utc>>>>,
+    ) -> Self {
+        Self {
+            receiver: Arc::new(Mutex::new(receiver)),
+            abort_handle: Some(abort_handle),
+            resumption_token,
+        }
+    }
+}
+
+#[pymethods]
+impl PyEventStream {
+    fn __aiter__(slf: PyRef<'_, Self>) -> PyRef<'_, Self> {
+        slf
+    }
+
+    fn __anext__<'py>(&self, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let receiver = self.receiver.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut rx = receiver.lock().await;
+            match rx.recv().await {
+                Some(Ok(event)) => Ok(Some(event)),
+                Some(Err(e)) => Err(e.into()),
+                None => Err(pyo3::exceptions::PyStopAsyncIteration::new_err(())), // Stream exhausted - signals StopAsyncIteration
+            }
+        })
+    }
+
+    /// RFC 3339 timestamp of the last event this stream delivered, or
+    /// `None` if none has been delivered yet. Pass this into
+    /// `RunConfig(resumable=True, resumption_token=...)` for the next
+    /// `run_stream()` call to replay anything recorded after a dropped
+    /// connection instead of missing it.
+    fn resumption_token(&self) -> Option<String> {
+        self.resumption_token
+            .lock()
+            .unwrap()
+            .map(|t| t.to_rfc3339())
+    }
+
+    /// Abort the background task feeding this stream, if it's still
+    /// running. A no-op if the task already finished or the stream was
+    /// never cancellable in the first place.
+    fn cancel(&self) {
+        if let Some(handle) = &self.abort_handle {
+            handle.abort();
+        }
+    }
+
+    fn __aenter__<'py>(slf: Bound<'py, Self>, py: Python<'py>) -> PyResult<Bound<'py, PyAny>> {
+        let stream = slf.into_any().unbind();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move { Ok(stream) })
+    }
+
+    /// Cancel the background task and drain any events already buffered in
+    /// the channel, so nothing is left pending once the `async with` block
+    /// exits. Never suppresses an exception raised in the block.
+    #[pyo3(signature = (_exc_type=None, _exc_value=None, _traceback=None))]
+    fn __aexit__<'py>(
+        &self,
+        py: Python<'py>,
+        _exc_type: Option<Py<PyAny>>,
+        _exc_value: Option<Py<PyAny>>,
+        _traceback: Option<Py<PyAny>>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        self.cancel();
+        let receiver = self.receiver.clone();
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let mut rx = receiver.lock().await;
+            while rx.recv().await.is_some() {}
+            Ok(false)
+        })
+    }
+}
+
+/// Runner for executing agents
+#[pyclass(name = "Runner")]
+pub struct PyRunner {
+    app_name: String,
+    agent: Arc<dyn adk_core::Agent>,
+    session_service: Arc<dyn adk_session::SessionService>,
+    run_config: Option<adk_core::RunConfig>,
+    /// Parsed from `run_config.resumption_token` when `resumable` is set;
+    /// `adk_core::RunConfig` has no notion of this, so it's carried
+    /// alongside rather than folded into the `.into()` conversion below.
+    resume_after: Option<DateTime<Utc>>,
+}
+
+#[pymethods]
+impl PyRunner {
+    #[new]
+    #[pyo3(signature = (app_name, agent, session_service, run_config=None))]
+    fn new(
+        app_name: String,
+        agent: &PyLlmAgent,
+        session_service: &Bound<'_, PyAny>,
+        run_config: Option<&PyRunConfig>,
+    ) -> PyResult<Self> {
+        let resume_after = run_config
+            .filter(|c| c.resumable)
+            .and_then(|c| c.resumption_token.as_deref())
+            .map(DateTime::parse_from_rfc3339)
+            .transpose()
+            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?
+            .map(|t| t.with_timezone(&Utc));
+
+        Ok(Self {
+            app_name,
+            agent: agent.inner.clone(),
+            session_service: extract_session_service(session_service)?,
+            run_config: run_config.map(|c| c.clone().into()),
+            resume_after,
+        })
+    }
+
+    /// Run the agent with the given user message, returning all events
+    fn run<'py>(
+        &self,
+        py: Python<'py>,
+        user_id: String,
+        session_id: String,
+        message: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let agent = self.agent.clone();
+        let session_service = self.session_service.clone();
+        let app_name = self.app_name.clone();
+        let run_config = self.run_config.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let user_content = adk_core::Content::new("user").with_text(&message);
+
+            let config = adk_runner::RunnerConfig {
+                app_name,
+                agent,
+                session_service,
+                artifact_service: None,
+                memory_service: None,
+                run_config,
+            };
+
+            let runner = adk_runner::Runner::new(config)
+                .map_err(|e| crate::error::adk_error_to_pyerr(&e))?;
+
+            let stream_result = runner.run(user_id, session_id, user_content).await;
+            let mut stream = stream_result.map_err(|e| crate::error::adk_error_to_pyerr(&e))?;
+
+            let mut events = Vec::new();
+
+            while let Some(result) = stream.next().await {
+                match result {
+                    Ok(event) => events.push(PyEvent::from(event)),
+                    Err(e) => return Err(crate::error::adk_error_to_pyerr(&e)),
+                }
+            }
+
+            Ok(events)
+        })
+    }
+
+    /// Run the agent and return just the final response text
+    fn run_simple<'py>(
+        &self,
+        py: Python<'py>,
+        user_id: String,
+        session_id: String,
+        message: String,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let agent = self.agent.clone();
+        let session_service = self.session_service.clone();
+        let app_name = self.app_name.clone();
+        let run_config = self.run_config.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let user_content = adk_core::Content::new("user").with_text(&message);
+
+            let config = adk_runner::RunnerConfig {
+                app_name,
+                agent,
+                session_service,
+                artifact_service: None,
+                memory_service: None,
+                run_config,
+            };
+
+            let runner = adk_runner::Runner::new(config)
+                .map_err(|e| crate::error::adk_error_to_pyerr(&e))?;
+
+            let stream_result = runner.run(user_id, session_id, user_content).await;
+            let mut stream = stream_result.map_err(|e| crate::error::adk_error_to_pyerr(&e))?;
+
+            let mut final_text = String::new();
+
+            while let Some(result) = stream.next().await {
+                match result {
+                    Ok(event) => {
+                        if event.is_final_response() {
+                            if let Some(content) = event.content() {
+                                for part in content.parts.iter() {
+                                    if let Some(text) = part.text() {
+                                        final_text.push_str(text);
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => return Err(crate::error::adk_error_to_pyerr(&e)),
+                }
+            }
+
+            Ok(final_text)
+        })
+    }
+
+    /// Run the agent like `run()`, but without requiring an `asyncio` event
+    /// loop: the work is spawned on the shared Tokio runtime immediately and
+    /// a `Promise` handle is returned. Call `.wait()` on it to block the
+    /// calling thread for the event list, or `.is_done()` to poll.
+    fn run_blocking(
+        &self,
+        user_id: String,
+        session_id: String,
+        message: String,
+    ) -> PyResult<PyPromise> {
+        let agent = self.agent.clone();
+        let session_service = self.session_service.clone();
+        let app_name = self.app_name.clone();
+        let run_config = self.run_config.clone();
+
+        Ok(PyPromise::spawn(async move {
+            let user_content = adk_core::Content::new("user").with_text(&message);
+
+            let config = adk_runner::RunnerConfig {
+                app_name,
+                agent,
+                session_service,
+                artifact_service: None,
+                memory_service: None,
+                run_config,
+            };
+
+            let runner = adk_runner::Runner::new(config)?;
+
+            let mut stream = runner.run(user_id, session_id, user_content).await?;
+
+            let mut events = Vec::new();
+            while let Some(result) = stream.next().await {
+                events.push(PyEvent::from(result?));
+            }
+
+            Python::with_gil(|py| Ok(events.into_py(py)))
+        }))
+    }
+
+    /// Run the agent like `run_simple()`, but without requiring an `asyncio`
+    /// event loop; see `run_blocking()` for the `Promise` contract.
+    fn run_simple_blocking(
+        &self,
+        user_id: String,
+        session_id: String,
+        message: String,
+    ) -> PyResult<PyPromise> {
+        let agent = self.agent.clone();
+        let session_service = self.session_service.clone();
+        let app_name = self.app_name.clone();
+        let run_config = self.run_config.clone();
+
+        Ok(PyPromise::spawn(async move {
+            let user_content = adk_core::Content::new("user").with_text(&message);
+
+            let config = adk_runner::RunnerConfig {
+                app_name,
+                agent,
+                session_service,
+                artifact_service: None,
+                memory_service: None,
+                run_config,
+            };
+
+            let runner = adk_runner::Runner::new(config)?;
+
+            let mut stream = runner.run(user_id, session_id, user_content).await?;
+
+            let mut final_text = String::new();
+            while let Some(result) = stream.next().await {
+                let event = result?;
+                if event.is_final_response() {
+                    if let Some(content) = event.content() {
+                        for part in content.parts.iter() {
+                            if let Some(text) = part.text() {
+                                final_text.push_str(text);
+                            }
+                        }
+                    }
+                }
+            }
+
+            Python::with_gil(|py| Ok(final_text.into_py(py)))
+        }))
+    }
+
+    /// Run the agent with streaming - returns an async iterator of events.
+    ///
+    /// Unlike `run()`/`run_simple()`, this already returns immediately
+    /// without needing an event loop (the returned `EventStream` has no
+    /// `run_stream_blocking` counterpart - consuming it still means
+    /// awaiting events one at a time).
+    ///
+    /// Use with `async for`:
+    /// ```python
+    /// async for event in runner.run_stream(user_id, session_id, message):
+    ///     if text := event.get_text():
+    ///         print(text, end="", flush=True)
+    /// ```
+    fn run_stream(
+        &self,
+        _py: Python<'_>,
+        user_id: String,
+        session_id: String,
+        message: String,
+    ) -> PyResult<PyEventStream> {
+        let agent = self.agent.clone();
+        let session_service = self.session_service.clone();
+        let app_name = self.app_name.clone();
+        let run_config = self.run_config.clone();
+        let resume_after = self.resume_after;
+
+        // Create a channel for sending events
+        let (tx, rx) = tokio::sync::mpsc::channel(32);
+        let resumption_token = Arc::new(std::sync::Mutex::new(None::<DateTime<Utc>>));
+        let resumption_token_producer = resumption_token.clone();
+
+        // Spawn a task that reads from the Rust stream and sends to the
+        // channel. Goes through `spawn_gil_free` rather than the runtime
+        // directly: see the invariant documented on that helper.
+        let join_handle = crate::promise::spawn_gil_free(async move {
+            // Stamps the cursor with the event's own `timestamp` rather than
+            // a session-service lookup: that would add a round-trip per
+            // forwarded event (expensive for a token-by-token Bidi stream),
+            // and `last_update_time()` reflects whichever write landed last
+            // across *every* writer, so a concurrent `append_event` from
+            // elsewhere (e.g. a `subscribe`-driven writer) could bump it
+            // past an event this stream hasn't sent yet. The event's own
+            // timestamp only reflects this event, so it's race-free and
+            // free to read.
+            async fn forward(
+                tx: &tokio::sync::mpsc::Sender<Result<PyEvent, crate::error::AdkPyError>>,
+                resumption_token: &std::sync::Mutex<Option<DateTime<Utc>>>,
+                timestamp: DateTime<Utc>,
+                event: PyEvent,
+            ) -> bool {
+                let sent = tx.send(Ok(event)).await.is_ok();
+                if sent {
+                    *resumption_token.lock().unwrap() = Some(timestamp);
+                }
+                sent
+            }
+
+            // Replay anything recorded since `resume_after` before
+            // resuming the live stream, so a reconnecting client doesn't
+            // miss events emitted while it was disconnected. Best-effort:
+            // a lookup failure here (e.g. the session is gone) just means
+            // there's nothing to replay, not that the run itself should
+            // fail.
+            if let Some(after) = resume_after {
+                if let Ok(session) = session_service
+                    .get(adk_session::GetRequest {
+                        app_name: app_name.clone(),
+                        user_id: user_id.clone(),
+                        session_id: session_id.clone(),
+                        num_recent_events: None,
+                        after: Some(after),
+                    })
+                    .await
+                {
+                    for event in session.events().all() {
+                        let timestamp = event.timestamp;
+                        if !forward(
+                            &tx,
+                            &resumption_token_producer,
+                            timestamp,
+                            PyEvent::from(event),
+                        )
+                        .await
+                        {
+                            return;
+                        }
+                    }
+                }
+            }
+
+            let user_content = adk_core::Content::new("user").with_text(&message);
+
+            let config = adk_runner::RunnerConfig {
+                app_name,
+                agent,
+                session_service,
+                artifact_service: None,
+                memory_service: None,
+                run_config,
+            };
+
+            let runner = match adk_runner::Runner::new(config) {
+                Ok(r) => r,
+                Err(e) => {
+                    let _ = tx.send(Err(e.into())).await;
+                    return;
+                }
+            };
+
+            let stream_result = runner.run(user_id, session_id, user_content).await;
+            let mut stream = match stream_result {
+                Ok(s) => s,
+                Err(e) => {
+                    let _ = tx.send(Err(e.into())).await;
+                    return;
+                }
+            };
+
+            while let Some(result) = stream.next().await {
+                let keep_going = match result {
+                    Ok(event) => {
+                        let timestamp = event.timestamp;
+                        forward(
+                            &tx,
+                            &resumption_token_producer,
+                            timestamp,
+                            PyEvent::from(event),
+                        )
+                        .await
+                    }
+                    Err(e) => tx.send(Err(e.into())).await.is_ok(),
+                };
+
+                if !keep_going {
+                    // Receiver dropped, stop sending
+                    break;
+                }
+            }
+            // Channel closes when tx is dropped
+        });
+
+        Ok(PyEventStream::new_resumable(
+            rx,
+            join_handle.abort_handle(),
+            resumption_token,
+        ))
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Runner(app_name='{}')", self.app_name)
+    }
+}
+
+/// Simple function to run an agent once
+#[pyfunction]
+#[pyo3(signature = (agent, message, user_id="default_user", session_id="default_session", app_name="adk_app"))]
+pub fn run_agent<'py>(
+    py: Python<'py>,
+    agent: &PyLlmAgent,
+    message: String,
+    user_id: &str,
+    session_id: &str,
+    app_name: &str,
+) -> PyResult<Bound<'py, PyAny>> {
+    let agent = agent.inner.clone();
+    let user_id = user_id.to_string();
+    let session_id = session_id.to_string();
+    let app_name = app_name.to_string();
+
+    pyo3_async_runtimes::tokio::future_into_py(py, async move {
+        let user_content = adk_core::Content::new("user").with_text(&message);
+        let session_service = Arc::new(adk_session::InMemorySessionService::new());
+
+        // Create session first (required by runner)
+        session_service
+            .create(adk_session::CreateRequest {
+                app_name: app_name.clone(),
+                user_id: user_id.clone(),
+                session_id: Some(session_id.clone()),
+                state: Default::default(),
+            })
+            .await
+            .map_err(|e| crate::error::SessionError::new_err(e.to_string()))?;
+
+        let config = adk_runner::RunnerConfig {
+            app_name,
+            agent,
+            session_service,
+            artifact_service: None,
+            memory_service: None,
+            run_config: None,
+        };
+
+        let runner =
+            adk_runner::Runner::new(config).map_err(|e| crate::error::adk_error_to_pyerr(&e))?;
+
+        let stream_result = runner.run(user_id, session_id, user_content).await;
+        let mut stream = stream_result.map_err(|e| crate::error::adk_error_to_pyerr(&e))?;
+
+        let mut final_text = String::new();
+
+        while let Some(result) = stream.next().await {
+            match result {
+                Ok(event) => {
+                    if event.is_final_response() {
+                        if let Some(content) = event.content() {
+                            for part in content.parts.iter() {
+                                if let Some(text) = part.text() {
+                                    final_text.push_str(text);
+                                }
+                            }
+                        }
+                    }
+                }
+                Err(e) => return Err(crate::error::adk_error_to_pyerr(&e)),
+            }
+        }
+
+        Ok(final_text)
+    })
+}
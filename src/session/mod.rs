@@ -1,642 +1,1465 @@
-//! Session management bindings for Python
-//!
-//! This module provides Python bindings for session management:
-//! - `InMemorySessionService` - In-memory session storage with full CRUD
-//! - `Session` - Session wrapper with access to id, state, events
-//! - `State` - Session state (key-value store)
-//! - `RunConfig` - Agent execution configuration
-//! - `StreamingMode` - Streaming behavior enum
-//! - `CreateSessionRequest` - Request to create a session
-//! - `GetSessionRequest` - Request to retrieve a session
-//! - `ListSessionRequest` - Request to list sessions
-//! - `DeleteSessionRequest` - Request to delete a session
-
-use adk_session::SessionService;
-use chrono::{DateTime, Utc};
-use pyo3::prelude::*;
-use std::collections::HashMap;
-use std::sync::Arc;
-
-use crate::types::PyEvent;
-
-/// Session wrapper providing access to session data
-#[pyclass(name = "Session")]
-#[derive(Clone)]
-pub struct PySession {
-    id: String,
-    app_name: String,
-    user_id: String,
-    state: PyState,
-    events: Vec<PyEvent>,
-    last_update_time: DateTime<Utc>,
-}
-
-#[pymethods]
-impl PySession {
-    /// Get the session ID
-    #[getter]
-    fn id(&self) -> &str {
-        &self.id
-    }
-
-    /// Get the application name
-    #[getter]
-    fn app_name(&self) -> &str {
-        &self.app_name
-    }
-
-    /// Get the user ID
-    #[getter]
-    fn user_id(&self) -> &str {
-        &self.user_id
-    }
-
-    /// Get the session state
-    #[getter]
-    fn state(&self) -> PyState {
-        self.state.clone()
-    }
-
-    /// Get all events in the session
-    #[getter]
-    fn events(&self) -> Vec<PyEvent> {
-        self.events.clone()
-    }
-
-    /// Get the last update timestamp as ISO 8601 string
-    #[getter]
-    fn last_update_time(&self) -> String {
-        self.last_update_time.to_rfc3339()
-    }
-
-    /// Get the number of events in the session
-    fn event_count(&self) -> usize {
-        self.events.len()
-    }
-
-    fn __repr__(&self) -> String {
-        format!(
-            "Session(id='{}', app='{}', user='{}', events={})",
-            self.id,
-            self.app_name,
-            self.user_id,
-            self.events.len()
-        )
-    }
-}
-
-impl PySession {
-    /// Create from a Rust Session trait object
-    pub fn from_session(session: &dyn adk_session::Session) -> Self {
-        let events = session
-            .events()
-            .all()
-            .into_iter()
-            .map(PyEvent::from)
-            .collect();
-
-        Self {
-            id: session.id().to_string(),
-            app_name: session.app_name().to_string(),
-            user_id: session.user_id().to_string(),
-            state: PyState::from_session_state(session.state()),
-            events,
-            last_update_time: session.last_update_time(),
-        }
-    }
-}
-
-/// In-memory session service with full CRUD operations
-#[pyclass(name = "InMemorySessionService")]
-#[derive(Clone)]
-pub struct PyInMemorySessionService {
-    pub(crate) inner: Arc<adk_session::InMemorySessionService>,
-}
-
-#[pymethods]
-impl PyInMemorySessionService {
-    #[new]
-    fn new() -> Self {
-        Self {
-            inner: Arc::new(adk_session::InMemorySessionService::new()),
-        }
-    }
-
-    /// Create a new session
-    ///
-    /// Args:
-    ///     request: CreateSessionRequest with app_name, user_id, optional session_id
-    ///
-    /// Returns:
-    ///     Session: The created session
-    fn create<'py>(
-        &self,
-        py: Python<'py>,
-        request: &PyCreateSessionRequest,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        let service = self.inner.clone();
-        let req = adk_session::CreateRequest {
-            app_name: request.app_name.clone(),
-            user_id: request.user_id.clone(),
-            session_id: request.session_id.clone(),
-            state: request.state.clone(),
-        };
-
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let session = service
-                .create(req)
-                .await
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-            Ok(PySession::from_session(session.as_ref()))
-        })
-    }
-
-    /// Get an existing session
-    ///
-    /// Args:
-    ///     request: GetSessionRequest with app_name, user_id, session_id
-    ///
-    /// Returns:
-    ///     Session: The retrieved session
-    ///
-    /// Raises:
-    ///     RuntimeError: If session not found
-    fn get<'py>(
-        &self,
-        py: Python<'py>,
-        request: &PyGetSessionRequest,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        let service = self.inner.clone();
-        let req = adk_session::GetRequest {
-            app_name: request.app_name.clone(),
-            user_id: request.user_id.clone(),
-            session_id: request.session_id.clone(),
-            num_recent_events: request.num_recent_events,
-            after: request.after,
-        };
-
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let session = service
-                .get(req)
-                .await
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-            Ok(PySession::from_session(session.as_ref()))
-        })
-    }
-
-    /// List all sessions for a user
-    ///
-    /// Args:
-    ///     request: ListSessionRequest with app_name, user_id
-    ///
-    /// Returns:
-    ///     List[Session]: All sessions for the user
-    fn list<'py>(
-        &self,
-        py: Python<'py>,
-        request: &PyListSessionRequest,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        let service = self.inner.clone();
-        let req = adk_session::ListRequest {
-            app_name: request.app_name.clone(),
-            user_id: request.user_id.clone(),
-        };
-
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            let sessions = service
-                .list(req)
-                .await
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-            let py_sessions: Vec<PySession> = sessions
-                .iter()
-                .map(|s| PySession::from_session(s.as_ref()))
-                .collect();
-            Ok(py_sessions)
-        })
-    }
-
-    /// Delete a session
-    ///
-    /// Args:
-    ///     request: DeleteSessionRequest with app_name, user_id, session_id
-    ///
-    /// Raises:
-    ///     RuntimeError: If session not found
-    fn delete<'py>(
-        &self,
-        py: Python<'py>,
-        request: &PyDeleteSessionRequest,
-    ) -> PyResult<Bound<'py, PyAny>> {
-        let service = self.inner.clone();
-        let req = adk_session::DeleteRequest {
-            app_name: request.app_name.clone(),
-            user_id: request.user_id.clone(),
-            session_id: request.session_id.clone(),
-        };
-
-        pyo3_async_runtimes::tokio::future_into_py(py, async move {
-            service
-                .delete(req)
-                .await
-                .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
-            Ok(())
-        })
-    }
-
-    fn __repr__(&self) -> String {
-        "InMemorySessionService()".to_string()
-    }
-}
-
-/// Session state wrapper
-#[pyclass(name = "State")]
-#[derive(Clone)]
-pub struct PyState {
-    data: HashMap<String, serde_json::Value>,
-}
-
-#[pymethods]
-impl PyState {
-    #[new]
-    fn new() -> Self {
-        Self {
-            data: HashMap::new(),
-        }
-    }
-
-    fn get(&self, py: Python<'_>, key: &str) -> PyObject {
-        match self.data.get(key) {
-            Some(value) => pythonize::pythonize(py, value)
-                .map(|b| b.into())
-                .unwrap_or_else(|_| py.None()),
-            None => py.None(),
-        }
-    }
-
-    fn set(&mut self, key: String, value: &Bound<'_, PyAny>) -> PyResult<()> {
-        let json_value: serde_json::Value = pythonize::depythonize(value)
-            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
-        self.data.insert(key, json_value);
-        Ok(())
-    }
-
-    fn all(&self, py: Python<'_>) -> PyObject {
-        pythonize::pythonize(py, &self.data)
-            .map(|b| b.into())
-            .unwrap_or_else(|_| py.None())
-    }
-
-    fn contains(&self, key: &str) -> bool {
-        self.data.contains_key(key)
-    }
-
-    fn remove(&mut self, key: &str) -> bool {
-        self.data.remove(key).is_some()
-    }
-
-    fn keys(&self) -> Vec<String> {
-        self.data.keys().cloned().collect()
-    }
-
-    fn __len__(&self) -> usize {
-        self.data.len()
-    }
-
-    fn __repr__(&self) -> String {
-        format!("State(keys={})", self.data.len())
-    }
-}
-
-impl PyState {
-    /// Create an empty state
-    pub fn empty() -> Self {
-        Self {
-            data: HashMap::new(),
-        }
-    }
-
-    /// Create from adk_core::State
-    pub fn from_core_state(state: &dyn adk_core::State) -> Self {
-        Self { data: state.all() }
-    }
-
-    /// Create from adk_session::State
-    pub fn from_session_state(state: &dyn adk_session::State) -> Self {
-        Self { data: state.all() }
-    }
-}
-
-/// Request to create a new session
-#[pyclass(name = "CreateSessionRequest")]
-#[derive(Clone)]
-pub struct PyCreateSessionRequest {
-    pub(crate) app_name: String,
-    pub(crate) user_id: String,
-    pub(crate) session_id: Option<String>,
-    pub(crate) state: HashMap<String, serde_json::Value>,
-}
-
-#[pymethods]
-impl PyCreateSessionRequest {
-    #[new]
-    #[pyo3(signature = (app_name, user_id, session_id=None))]
-    fn new(app_name: String, user_id: String, session_id: Option<String>) -> Self {
-        Self {
-            app_name,
-            user_id,
-            session_id,
-            state: HashMap::new(),
-        }
-    }
-
-    #[getter]
-    fn app_name(&self) -> &str {
-        &self.app_name
-    }
-
-    #[getter]
-    fn user_id(&self) -> &str {
-        &self.user_id
-    }
-
-    #[getter]
-    fn session_id(&self) -> Option<&str> {
-        self.session_id.as_deref()
-    }
-
-    fn with_state<'a>(
-        mut slf: PyRefMut<'a, Self>,
-        key: String,
-        value: &Bound<'a, PyAny>,
-    ) -> PyResult<PyRefMut<'a, Self>> {
-        let json_value: serde_json::Value = pythonize::depythonize(value)
-            .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
-        slf.state.insert(key, json_value);
-        Ok(slf)
-    }
-
-    fn __repr__(&self) -> String {
-        format!(
-            "CreateSessionRequest(app='{}', user='{}', session={:?})",
-            self.app_name, self.user_id, self.session_id
-        )
-    }
-}
-
-/// Request to get an existing session
-#[pyclass(name = "GetSessionRequest")]
-#[derive(Clone)]
-pub struct PyGetSessionRequest {
-    pub(crate) app_name: String,
-    pub(crate) user_id: String,
-    pub(crate) session_id: String,
-    pub(crate) num_recent_events: Option<usize>,
-    pub(crate) after: Option<DateTime<Utc>>,
-}
-
-#[pymethods]
-impl PyGetSessionRequest {
-    #[new]
-    #[pyo3(signature = (app_name, user_id, session_id, num_recent_events=None))]
-    fn new(
-        app_name: String,
-        user_id: String,
-        session_id: String,
-        num_recent_events: Option<usize>,
-    ) -> Self {
-        Self {
-            app_name,
-            user_id,
-            session_id,
-            num_recent_events,
-            after: None,
-        }
-    }
-
-    #[getter]
-    fn app_name(&self) -> &str {
-        &self.app_name
-    }
-
-    #[getter]
-    fn user_id(&self) -> &str {
-        &self.user_id
-    }
-
-    #[getter]
-    fn session_id(&self) -> &str {
-        &self.session_id
-    }
-
-    #[getter]
-    fn num_recent_events(&self) -> Option<usize> {
-        self.num_recent_events
-    }
-
-    fn __repr__(&self) -> String {
-        format!(
-            "GetSessionRequest(app='{}', user='{}', session='{}')",
-            self.app_name, self.user_id, self.session_id
-        )
-    }
-}
-
-/// Request to list sessions for a user
-#[pyclass(name = "ListSessionRequest")]
-#[derive(Clone)]
-pub struct PyListSessionRequest {
-    pub(crate) app_name: String,
-    pub(crate) user_id: String,
-}
-
-#[pymethods]
-impl PyListSessionRequest {
-    #[new]
-    fn new(app_name: String, user_id: String) -> Self {
-        Self { app_name, user_id }
-    }
-
-    #[getter]
-    fn app_name(&self) -> &str {
-        &self.app_name
-    }
-
-    #[getter]
-    fn user_id(&self) -> &str {
-        &self.user_id
-    }
-
-    fn __repr__(&self) -> String {
-        format!(
-            "ListSessionRequest(app='{}', user='{}')",
-            self.app_name, self.user_id
-        )
-    }
-}
-
-/// Request to delete a session
-#[pyclass(name = "DeleteSessionRequest")]
-#[derive(Clone)]
-pub struct PyDeleteSessionRequest {
-    pub(crate) app_name: String,
-    pub(crate) user_id: String,
-    pub(crate) session_id: String,
-}
-
-#[pymethods]
-impl PyDeleteSessionRequest {
-    #[new]
-    fn new(app_name: String, user_id: String, session_id: String) -> Self {
-        Self {
-            app_name,
-            user_id,
-            session_id,
-        }
-    }
-
-    #[getter]
-    fn app_name(&self) -> &str {
-        &self.app_name
-    }
-
-    #[getter]
-    fn user_id(&self) -> &str {
-        &self.user_id
-    }
-
-    #[getter]
-    fn session_id(&self) -> &str {
-        &self.session_id
-    }
-
-    fn __repr__(&self) -> String {
-        format!(
-            "DeleteSessionRequest(app='{}', user='{}', session='{}')",
-            self.app_name, self.user_id, self.session_id
-        )
-    }
-}
-
-/// Streaming mode for agent execution
-#[pyclass(name = "StreamingMode", eq, eq_int)]
-#[derive(Clone, Copy, PartialEq, Eq, Debug)]
-pub enum PyStreamingMode {
-    None = 0,
-    SSE = 1,
-    Bidi = 2,
-}
-
-/// Run configuration for agent execution
-#[pyclass(name = "RunConfig")]
-#[derive(Clone)]
-pub struct PyRunConfig {
-    pub(crate) streaming_mode: PyStreamingMode,
-}
-
-#[pymethods]
-impl PyRunConfig {
-    #[new]
-    #[pyo3(signature = (streaming_mode=PyStreamingMode::SSE))]
-    fn new(streaming_mode: PyStreamingMode) -> Self {
-        Self { streaming_mode }
-    }
-
-    #[getter]
-    fn streaming_mode(&self) -> PyStreamingMode {
-        self.streaming_mode
-    }
-
-    fn __repr__(&self) -> String {
-        format!("RunConfig(streaming_mode={:?})", self.streaming_mode)
-    }
-}
-
-impl From<PyRunConfig> for adk_core::RunConfig {
-    fn from(config: PyRunConfig) -> Self {
-        let mode = match config.streaming_mode {
-            PyStreamingMode::None => adk_core::StreamingMode::None,
-            PyStreamingMode::SSE => adk_core::StreamingMode::SSE,
-            PyStreamingMode::Bidi => adk_core::StreamingMode::Bidi,
-        };
-        adk_core::RunConfig {
-            streaming_mode: mode,
-        }
-    }
-}
-
-/// Model generation configuration
-///
-/// Controls LLM generation parameters like temperature, top_p, etc.
-#[pyclass(name = "GenerateContentConfig")]
-#[derive(Clone, Default)]
-pub struct PyGenerateContentConfig {
-    #[pyo3(get, set)]
-    pub temperature: Option<f32>,
-    #[pyo3(get, set)]
-    pub top_p: Option<f32>,
-    #[pyo3(get, set)]
-    pub top_k: Option<i32>,
-    #[pyo3(get, set)]
-    pub max_output_tokens: Option<i32>,
-    response_schema: Option<serde_json::Value>,
-}
-
-#[pymethods]
-impl PyGenerateContentConfig {
-    #[new]
-    #[pyo3(signature = (temperature=None, top_p=None, top_k=None, max_output_tokens=None, response_schema=None))]
-    fn new(
-        temperature: Option<f32>,
-        top_p: Option<f32>,
-        top_k: Option<i32>,
-        max_output_tokens: Option<i32>,
-        response_schema: Option<&pyo3::Bound<'_, pyo3::types::PyDict>>,
-    ) -> PyResult<Self> {
-        let schema = if let Some(dict) = response_schema {
-            Some(
-                pythonize::depythonize::<serde_json::Value>(dict.as_any())
-                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?,
-            )
-        } else {
-            None
-        };
-
-        Ok(Self {
-            temperature,
-            top_p,
-            top_k,
-            max_output_tokens,
-            response_schema: schema,
-        })
-    }
-
-    /// Get response schema as a Python dict
-    #[getter]
-    fn response_schema(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
-        match &self.response_schema {
-            Some(schema) => pythonize::pythonize(py, schema)
-                .map(|b| b.into())
-                .unwrap_or_else(|_| py.None()),
-            None => py.None(),
-        }
-    }
-
-    fn __repr__(&self) -> String {
-        format!(
-            "GenerateContentConfig(temperature={:?}, top_p={:?}, top_k={:?}, max_output_tokens={:?})",
-            self.temperature, self.top_p, self.top_k, self.max_output_tokens
-        )
-    }
-}
-
-impl From<PyGenerateContentConfig> for adk_core::GenerateContentConfig {
-    fn from(config: PyGenerateContentConfig) -> Self {
-        Self {
-            temperature: config.temperature,
-            top_p: config.top_p,
-            top_k: config.top_k,
-            max_output_tokens: config.max_output_tokens,
-            response_schema: config.response_schema,
-        }
-    }
-}
+//! Session management bindings for Python
+//!
+//! This module provides Python bindings for session management:
+//! - `InMemorySessionService` - In-memory session storage with full CRUD
+//! - `SqliteSessionService` - SQLite-backed session storage with full CRUD
+//! - `Session` - Session wrapper with access to id, state, events
+//! - `State` - Session state (key-value store)
+//! - `RunConfig` - Agent execution configuration
+//! - `StreamingMode` - Streaming behavior enum
+//! - `CreateSessionRequest` - Request to create a session
+//! - `GetSessionRequest` - Request to retrieve a session
+//! - `ListSessionRequest` - Request to list sessions
+//! - `DeleteSessionRequest` - Request to delete a session
+//!
+//! `InMemorySessionService` and `SqliteSessionService` both implement
+//! `adk_session::SessionService`, so anything accepting a session service
+//! (`Runner`, `extract_session_service`) works with either interchangeably.
+//!
+//! `SqliteSessionService.connect(path, cache=True)` additionally keeps an
+//! in-process write-through cache of sessions it has created or fetched, so
+//! repeated `get()` calls for the same hot session skip the database.
+//!
+//! Both services also expose `append_event(session, event)` to record an
+//! agent turn and apply its state-delta (see `State`'s docs for the
+//! `app:`/`user:`/`temp:` key-prefix convention) without re-creating the
+//! session.
+//!
+//! `subscribe(app_name, user_id, session_id, callback)` registers a push
+//! callback that fires with each `Event` a subsequent `append_event` call
+//! records for that session, for callers that want to stream activity live
+//! instead of polling `get`/`event_count()`.
+
+use adk_session::SessionService;
+use chrono::{DateTime, Utc};
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use crate::types::PyEvent;
+
+/// Identifies one live `subscribe()` registration so `unsubscribe()` can
+/// remove just that callback, leaving sibling subscriptions on the same
+/// session untouched.
+type SubscriberId = u64;
+
+/// Live event-subscription callbacks, keyed by the same
+/// `(app_name, user_id, session_id)` triple as `HotSessionCache`.
+/// `append_event` fans the appended `PyEvent` out to every sender still
+/// registered for that session; a send failing (the subscriber's background
+/// task has exited) drops that entry on the next fan-out.
+type SessionSubscribers =
+    Mutex<HashMap<SessionKey, Vec<(SubscriberId, tokio::sync::mpsc::UnboundedSender<PyEvent>)>>>;
+
+fn notify_subscribers(subscribers: &SessionSubscribers, key: &SessionKey, event: &PyEvent) {
+    let mut map = subscribers.lock().unwrap();
+    if let Some(subs) = map.get_mut(key) {
+        subs.retain(|(_, tx)| tx.send(event.clone()).is_ok());
+        if subs.is_empty() {
+            map.remove(key);
+        }
+    }
+}
+
+/// Handle returned by `subscribe()`. Drop (or an explicit `unsubscribe()`
+/// call) stops the background task from receiving any further events for
+/// this registration - mirrors `telemetry::PyLoggingHandle`'s detach-on-drop
+/// shape, but keyed by session instead of by a single global slot.
+#[pyclass(name = "Subscription")]
+pub struct PySubscription {
+    id: SubscriberId,
+    key: SessionKey,
+    subscribers: Arc<SessionSubscribers>,
+}
+
+#[pymethods]
+impl PySubscription {
+    /// Stop receiving events. Safe to call more than once.
+    fn unsubscribe(&self) {
+        let mut map = self.subscribers.lock().unwrap();
+        if let Some(subs) = map.get_mut(&self.key) {
+            subs.retain(|(id, _)| *id != self.id);
+            if subs.is_empty() {
+                map.remove(&self.key);
+            }
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!("Subscription(session='{}')", self.key.2)
+    }
+}
+
+impl Drop for PySubscription {
+    fn drop(&mut self) {
+        self.unsubscribe();
+    }
+}
+
+/// Spawn the background task that drains `rx` and invokes `callback` with
+/// each event, used by both session services' `subscribe()`. The callback
+/// is classified once via `PythonCallback`/`detect_callable_kind` so an
+/// `async def` handler is awaited to completion instead of having its
+/// coroutine constructed and silently dropped, and each call runs on a
+/// blocking-pool thread rather than holding the GIL on this task's shared
+/// tokio runtime worker for the callback's duration.
+fn spawn_subscriber_task(
+    callback: Py<PyAny>,
+    mut rx: tokio::sync::mpsc::UnboundedReceiver<PyEvent>,
+) {
+    let callback = crate::callbacks::PythonCallback::new(callback);
+    crate::promise::spawn_gil_free(async move {
+        while let Some(event) = rx.recv().await {
+            callback.call_for_event(event).await;
+        }
+    });
+}
+
+/// Session wrapper providing access to session data
+#[pyclass(name = "Session")]
+#[derive(Clone)]
+pub struct PySession {
+    id: String,
+    app_name: String,
+    user_id: String,
+    state: PyState,
+    events: Vec<PyEvent>,
+    last_update_time: DateTime<Utc>,
+}
+
+#[pymethods]
+impl PySession {
+    /// Get the session ID
+    #[getter]
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    /// Get the application name
+    #[getter]
+    fn app_name(&self) -> &str {
+        &self.app_name
+    }
+
+    /// Get the user ID
+    #[getter]
+    fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    /// Get the session state
+    #[getter]
+    fn state(&self) -> PyState {
+        self.state.clone()
+    }
+
+    /// Get all events in the session
+    #[getter]
+    fn events(&self) -> Vec<PyEvent> {
+        self.events.clone()
+    }
+
+    /// Get the last update timestamp as ISO 8601 string
+    #[getter]
+    fn last_update_time(&self) -> String {
+        self.last_update_time.to_rfc3339()
+    }
+
+    /// Get the number of events in the session
+    fn event_count(&self) -> usize {
+        self.events.len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Session(id='{}', app='{}', user='{}', events={})",
+            self.id,
+            self.app_name,
+            self.user_id,
+            self.events.len()
+        )
+    }
+}
+
+impl PySession {
+    /// Create from a Rust Session trait object
+    pub fn from_session(session: &dyn adk_session::Session) -> Self {
+        let events = session
+            .events()
+            .all()
+            .into_iter()
+            .map(PyEvent::from)
+            .collect();
+
+        Self {
+            id: session.id().to_string(),
+            app_name: session.app_name().to_string(),
+            user_id: session.user_id().to_string(),
+            state: PyState::from_session_state(session.state()),
+            events,
+            last_update_time: session.last_update_time(),
+        }
+    }
+}
+
+/// In-memory session service with full CRUD operations
+#[pyclass(name = "InMemorySessionService")]
+#[derive(Clone)]
+pub struct PyInMemorySessionService {
+    pub(crate) inner: Arc<adk_session::InMemorySessionService>,
+    subscribers: Arc<SessionSubscribers>,
+    next_subscriber_id: Arc<AtomicU64>,
+}
+
+#[pymethods]
+impl PyInMemorySessionService {
+    #[new]
+    fn new() -> Self {
+        Self {
+            inner: Arc::new(adk_session::InMemorySessionService::new()),
+            subscribers: Arc::new(Mutex::new(HashMap::new())),
+            next_subscriber_id: Arc::new(AtomicU64::new(0)),
+        }
+    }
+
+    /// Create a new session
+    ///
+    /// Args:
+    ///     request: CreateSessionRequest with app_name, user_id, optional session_id
+    ///
+    /// Returns:
+    ///     Session: The created session
+    fn create<'py>(
+        &self,
+        py: Python<'py>,
+        request: &PyCreateSessionRequest,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let service = self.inner.clone();
+        let req = adk_session::CreateRequest {
+            app_name: request.app_name.clone(),
+            user_id: request.user_id.clone(),
+            session_id: request.session_id.clone(),
+            state: request.state.clone(),
+        };
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let session = service
+                .create(req)
+                .await
+                .map_err(|e| crate::error::SessionError::new_err(e.to_string()))?;
+            Ok(PySession::from_session(session.as_ref()))
+        })
+    }
+
+    /// Get an existing session
+    ///
+    /// Args:
+    ///     request: GetSessionRequest with app_name, user_id, session_id
+    ///
+    /// Returns:
+    ///     Session: The retrieved session
+    ///
+    /// Raises:
+    ///     SessionError: If session not found
+    fn get<'py>(
+        &self,
+        py: Python<'py>,
+        request: &PyGetSessionRequest,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let service = self.inner.clone();
+        let req = adk_session::GetRequest {
+            app_name: request.app_name.clone(),
+            user_id: request.user_id.clone(),
+            session_id: request.session_id.clone(),
+            num_recent_events: request.num_recent_events,
+            after: request.after,
+        };
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let session = service
+                .get(req)
+                .await
+                .map_err(|e| crate::error::SessionError::new_err(e.to_string()))?;
+            Ok(PySession::from_session(session.as_ref()))
+        })
+    }
+
+    /// List sessions for a user, optionally filtered and paginated
+    ///
+    /// Args:
+    ///     request: ListSessionRequest with app_name, user_id, and optional
+    ///         modified_after/modified_before/state_contains/limit/page_token
+    ///
+    /// Returns:
+    ///     SessionPage: The matching sessions, plus a next_page_token if
+    ///     more remain
+    fn list<'py>(
+        &self,
+        py: Python<'py>,
+        request: &PyListSessionRequest,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let service = self.inner.clone();
+        let req = adk_session::ListRequest {
+            app_name: request.app_name.clone(),
+            user_id: request.user_id.clone(),
+        };
+        let request = request.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let sessions = service
+                .list(req)
+                .await
+                .map_err(|e| crate::error::SessionError::new_err(e.to_string()))?;
+            let py_sessions: Vec<PySession> = sessions
+                .iter()
+                .map(|s| PySession::from_session(s.as_ref()))
+                .collect();
+            paginate_sessions(py_sessions, &request)
+        })
+    }
+
+    /// Delete a session
+    ///
+    /// Args:
+    ///     request: DeleteSessionRequest with app_name, user_id, session_id
+    ///
+    /// Raises:
+    ///     SessionError: If session not found
+    fn delete<'py>(
+        &self,
+        py: Python<'py>,
+        request: &PyDeleteSessionRequest,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let service = self.inner.clone();
+        let req = adk_session::DeleteRequest {
+            app_name: request.app_name.clone(),
+            user_id: request.user_id.clone(),
+            session_id: request.session_id.clone(),
+        };
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            service
+                .delete(req)
+                .await
+                .map_err(|e| crate::error::SessionError::new_err(e.to_string()))?;
+            Ok(())
+        })
+    }
+
+    /// Append an event to a session, atomically applying any state-delta it
+    /// carries.
+    ///
+    /// Args:
+    ///     session: Session to append to (its id/app_name/user_id address
+    ///         the stored session; the caller's in-memory `Session` snapshot
+    ///         is not mutated in place).
+    ///     event: Event to record. `event.get_state_delta()` keys prefixed
+    ///         `app:`/`user:` scope the write to the app/user tier, `temp:`
+    ///         keys are applied for the session service's own bookkeeping
+    ///         but never persisted, and unprefixed keys stay session-local -
+    ///         last writer wins per key.
+    ///
+    /// Returns:
+    ///     Session: The session after the event and its state-delta are
+    ///     applied, with `last_update_time` bumped.
+    fn append_event<'py>(
+        &self,
+        py: Python<'py>,
+        session: &PySession,
+        event: &PyEvent,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let service = self.inner.clone();
+        let key = (
+            session.app_name.clone(),
+            session.user_id.clone(),
+            session.id.clone(),
+        );
+        let req = adk_session::AppendEventRequest {
+            app_name: session.app_name.clone(),
+            user_id: session.user_id.clone(),
+            session_id: session.id.clone(),
+            event: adk_core::Event::from(event.clone()),
+        };
+        let subscribers = self.subscribers.clone();
+        let appended = event.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let session = service
+                .append_event(req)
+                .await
+                .map_err(|e| crate::error::SessionError::new_err(e.to_string()))?;
+            notify_subscribers(&subscribers, &key, &appended);
+            Ok(PySession::from_session(session.as_ref()))
+        })
+    }
+
+    /// Register `callback` to be invoked with each `Event` appended to the
+    /// given session via `append_event`, instead of polling
+    /// `event_count()`.
+    ///
+    /// Args:
+    ///     app_name: Application name the session belongs to.
+    ///     user_id: User the session belongs to.
+    ///     session_id: Session to watch.
+    ///     callback: Called with each new `Event`, `async def` or plain
+    ///         function alike. Runs on its own blocking-pool thread rather
+    ///         than this subscription's delivery task, but still one event
+    ///         at a time - a slow handler delays the next event on this
+    ///         subscription, not on any other subscription or run.
+    ///
+    /// Returns:
+    ///     Subscription: Call `unsubscribe()` (or let it drop) to stop
+    ///     receiving events.
+    fn subscribe(
+        &self,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        callback: Py<PyAny>,
+    ) -> PySubscription {
+        let key = (app_name, user_id, session_id);
+        let id = self.next_subscriber_id.fetch_add(1, Ordering::Relaxed);
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        spawn_subscriber_task(callback, rx);
+
+        self.subscribers
+            .lock()
+            .unwrap()
+            .entry(key.clone())
+            .or_default()
+            .push((id, tx));
+
+        PySubscription {
+            id,
+            key,
+            subscribers: self.subscribers.clone(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        "InMemorySessionService()".to_string()
+    }
+}
+
+/// Key a cached session by the triple its requests are scoped on.
+type SessionKey = (String, String, String);
+
+/// Opt-in write-through cache of recently seen sessions, keyed by
+/// `(app_name, user_id, session_id)`. `get()` serves a hit without touching
+/// SQLite; `create()`/`get()` misses and `delete()` keep it in sync. A
+/// session mutated by another process (or another `SqliteSessionService`
+/// instance) between a cache hit and its next write won't be reflected here
+/// until that instance's own next miss or eviction - the tradeoff this
+/// cache exists to make, for callers with hot, mostly-single-writer
+/// sessions (e.g. one process per active conversation).
+type HotSessionCache = Mutex<HashMap<SessionKey, PySession>>;
+
+/// SQLite-backed session service with full CRUD operations.
+///
+/// Sessions, their events, and state deltas are persisted to a SQLite
+/// database file, so they survive process restarts. Each `create`/`get`/
+/// `list`/`delete` call mirrors `InMemorySessionService`'s request types,
+/// so a `Runner` built against one can be pointed at the other without any
+/// other code changing. `adk_session::SqliteSessionService` applies an
+/// event's `state_delta` and its own insertion in one transaction, so a
+/// crash mid-turn can't leave a session with the event recorded but the
+/// state unapplied (or vice versa).
+#[pyclass(name = "SqliteSessionService")]
+#[derive(Clone)]
+pub struct PySqliteSessionService {
+    pub(crate) inner: Arc<adk_session::SqliteSessionService>,
+    cache: Option<Arc<HotSessionCache>>,
+    subscribers: Arc<SessionSubscribers>,
+    next_subscriber_id: Arc<AtomicU64>,
+}
+
+#[pymethods]
+impl PySqliteSessionService {
+    /// Open (creating if needed) a SQLite database at `path` and run its
+    /// migrations.
+    ///
+    /// Args:
+    ///     path: Filesystem path to the SQLite database file.
+    ///     cache: If `True`, keep an in-process write-through cache of
+    ///         sessions this instance has created or fetched, so repeat
+    ///         `get()` calls for the same hot session skip SQLite entirely.
+    ///         Off by default - see `SqliteSessionService`'s docs for the
+    ///         staleness tradeoff it makes.
+    ///
+    /// Returns:
+    ///     SqliteSessionService: Ready to pass to `Runner`.
+    #[staticmethod]
+    #[pyo3(signature = (path, cache=false))]
+    fn connect(py: Python<'_>, path: String, cache: bool) -> PyResult<Bound<'_, PyAny>> {
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let service = adk_session::SqliteSessionService::connect(&path)
+                .await
+                .map_err(|e| crate::error::SessionError::new_err(e.to_string()))?;
+            Ok(Self {
+                inner: Arc::new(service),
+                cache: cache.then(|| Arc::new(Mutex::new(HashMap::new()))),
+                subscribers: Arc::new(Mutex::new(HashMap::new())),
+                next_subscriber_id: Arc::new(AtomicU64::new(0)),
+            })
+        })
+    }
+
+    /// Create a new session
+    ///
+    /// Args:
+    ///     request: CreateSessionRequest with app_name, user_id, optional session_id
+    ///
+    /// Returns:
+    ///     Session: The created session
+    fn create<'py>(
+        &self,
+        py: Python<'py>,
+        request: &PyCreateSessionRequest,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let service = self.inner.clone();
+        let cache = self.cache.clone();
+        let req = adk_session::CreateRequest {
+            app_name: request.app_name.clone(),
+            user_id: request.user_id.clone(),
+            session_id: request.session_id.clone(),
+            state: request.state.clone(),
+        };
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let session = service
+                .create(req)
+                .await
+                .map_err(|e| crate::error::SessionError::new_err(e.to_string()))?;
+            let py_session = PySession::from_session(session.as_ref());
+            if let Some(cache) = &cache {
+                let key = (
+                    py_session.app_name.clone(),
+                    py_session.user_id.clone(),
+                    py_session.id.clone(),
+                );
+                cache.lock().unwrap().insert(key, py_session.clone());
+            }
+            Ok(py_session)
+        })
+    }
+
+    /// Get an existing session
+    ///
+    /// Args:
+    ///     request: GetSessionRequest with app_name, user_id, session_id
+    ///
+    /// Returns:
+    ///     Session: The retrieved session
+    ///
+    /// Raises:
+    ///     SessionError: If session not found
+    fn get<'py>(
+        &self,
+        py: Python<'py>,
+        request: &PyGetSessionRequest,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let service = self.inner.clone();
+        let cache = self.cache.clone();
+        let key = (
+            request.app_name.clone(),
+            request.user_id.clone(),
+            request.session_id.clone(),
+        );
+        let req = adk_session::GetRequest {
+            app_name: request.app_name.clone(),
+            user_id: request.user_id.clone(),
+            session_id: request.session_id.clone(),
+            num_recent_events: request.num_recent_events,
+            after: request.after,
+        };
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            if let Some(cache) = &cache {
+                if let Some(hit) = cache.lock().unwrap().get(&key) {
+                    return Ok(hit.clone());
+                }
+            }
+
+            let session = service
+                .get(req)
+                .await
+                .map_err(|e| crate::error::SessionError::new_err(e.to_string()))?;
+            let py_session = PySession::from_session(session.as_ref());
+            if let Some(cache) = &cache {
+                cache.lock().unwrap().insert(key, py_session.clone());
+            }
+            Ok(py_session)
+        })
+    }
+
+    /// List all sessions for a user
+    ///
+    /// Only `app_name` and `user_id` are applied; the request's time-window,
+    /// `with_state_contains`, `limit` and `page_token` settings are ignored.
+    ///
+    /// Args:
+    ///     request: ListSessionRequest with app_name, user_id
+    ///
+    /// Returns:
+    ///     List[Session]: All sessions for the user
+    fn list<'py>(
+        &self,
+        py: Python<'py>,
+        request: &PyListSessionRequest,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let service = self.inner.clone();
+        let req = adk_session::ListRequest {
+            app_name: request.app_name.clone(),
+            user_id: request.user_id.clone(),
+        };
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let sessions = service
+                .list(req)
+                .await
+                .map_err(|e| crate::error::SessionError::new_err(e.to_string()))?;
+            let py_sessions: Vec<PySession> = sessions
+                .iter()
+                .map(|s| PySession::from_session(s.as_ref()))
+                .collect();
+            Ok(py_sessions)
+        })
+    }
+
+    /// Delete a session
+    ///
+    /// Args:
+    ///     request: DeleteSessionRequest with app_name, user_id, session_id
+    ///
+    /// Raises:
+    ///     SessionError: If session not found
+    fn delete<'py>(
+        &self,
+        py: Python<'py>,
+        request: &PyDeleteSessionRequest,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let service = self.inner.clone();
+        let cache = self.cache.clone();
+        let key = (
+            request.app_name.clone(),
+            request.user_id.clone(),
+            request.session_id.clone(),
+        );
+        let req = adk_session::DeleteRequest {
+            app_name: request.app_name.clone(),
+            user_id: request.user_id.clone(),
+            session_id: request.session_id.clone(),
+        };
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            service
+                .delete(req)
+                .await
+                .map_err(|e| crate::error::SessionError::new_err(e.to_string()))?;
+            if let Some(cache) = &cache {
+                cache.lock().unwrap().remove(&key);
+            }
+            Ok(())
+        })
+    }
+
+    /// Append an event to a session, atomically applying any state-delta it
+    /// carries.
+    ///
+    /// Args:
+    ///     session: Session to append to (its id/app_name/user_id address
+    ///         the stored session; the caller's in-memory `Session` snapshot
+    ///         is not mutated in place).
+    ///     event: Event to record. `event.get_state_delta()` keys prefixed
+    ///         `app:`/`user:` scope the write to the app/user tier, `temp:`
+    ///         keys are applied for the session service's own bookkeeping
+    ///         but never persisted, and unprefixed keys stay session-local -
+    ///         last writer wins per key.
+    ///
+    /// Returns:
+    ///     Session: The session after the event and its state-delta are
+    ///     applied, with `last_update_time` bumped.
+    fn append_event<'py>(
+        &self,
+        py: Python<'py>,
+        session: &PySession,
+        event: &PyEvent,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let service = self.inner.clone();
+        let cache = self.cache.clone();
+        let key = (
+            session.app_name.clone(),
+            session.user_id.clone(),
+            session.id.clone(),
+        );
+        let req = adk_session::AppendEventRequest {
+            app_name: session.app_name.clone(),
+            user_id: session.user_id.clone(),
+            session_id: session.id.clone(),
+            event: adk_core::Event::from(event.clone()),
+        };
+        let subscribers = self.subscribers.clone();
+        let appended = event.clone();
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let session = service
+                .append_event(req)
+                .await
+                .map_err(|e| crate::error::SessionError::new_err(e.to_string()))?;
+            let py_session = PySession::from_session(session.as_ref());
+            if let Some(cache) = &cache {
+                cache
+                    .lock()
+                    .unwrap()
+                    .insert(key.clone(), py_session.clone());
+            }
+            notify_subscribers(&subscribers, &key, &appended);
+            Ok(py_session)
+        })
+    }
+
+    /// Register `callback` to be invoked with each `Event` appended to the
+    /// given session via `append_event`, instead of polling
+    /// `event_count()`.
+    ///
+    /// Args:
+    ///     app_name: Application name the session belongs to.
+    ///     user_id: User the session belongs to.
+    ///     session_id: Session to watch.
+    ///     callback: Called with each new `Event`, `async def` or plain
+    ///         function alike. Runs on its own blocking-pool thread rather
+    ///         than this subscription's delivery task, but still one event
+    ///         at a time - a slow handler delays the next event on this
+    ///         subscription, not on any other subscription or run.
+    ///
+    /// Returns:
+    ///     Subscription: Call `unsubscribe()` (or let it drop) to stop
+    ///     receiving events.
+    fn subscribe(
+        &self,
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        callback: Py<PyAny>,
+    ) -> PySubscription {
+        let key = (app_name, user_id, session_id);
+        let id = self.next_subscriber_id.fetch_add(1, Ordering::Relaxed);
+        let (tx, rx) = tokio::sync::mpsc::unbounded_channel();
+        spawn_subscriber_task(callback, rx);
+
+        self.subscribers
+            .lock()
+            .unwrap()
+            .entry(key.clone())
+            .or_default()
+            .push((id, tx));
+
+        PySubscription {
+            id,
+            key,
+            subscribers: self.subscribers.clone(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        match &self.cache {
+            Some(_) => "SqliteSessionService(cache=True)".to_string(),
+            None => "SqliteSessionService()".to_string(),
+        }
+    }
+}
+
+/// Extract a session service trait object from any session service type.
+///
+/// Mirrors `crate::model::extract_llm`: lets `Runner` and similar entry
+/// points accept `InMemorySessionService`, `SqliteSessionService`, or any
+/// future backend interchangeably.
+pub fn extract_session_service(
+    obj: &Bound<'_, PyAny>,
+) -> PyResult<Arc<dyn adk_session::SessionService>> {
+    if let Ok(service) = obj.extract::<PyRef<'_, PyInMemorySessionService>>() {
+        return Ok(service.inner.clone());
+    }
+    if let Ok(service) = obj.extract::<PyRef<'_, PySqliteSessionService>>() {
+        return Ok(service.inner.clone());
+    }
+    Err(pyo3::exceptions::PyTypeError::new_err(
+        "Expected a session service type (InMemorySessionService or SqliteSessionService)",
+    ))
+}
+
+/// Session state wrapper.
+///
+/// A state handed out by a context (`InvocationContext`/`CallbackContext`)
+/// tracks writes as a pending delta rather than mutating its snapshot
+/// directly: `set`/`remove` record the change in `pending_delta`, while
+/// `get`/`all`/`contains` merge that delta over the base so the same turn
+/// observes its own writes. When the turn ends, the pending delta is
+/// drained into the emitted event's `state_delta`, which session services
+/// replay to persist it - this is the same collect-then-flush shape the
+/// batch tool execution path uses.
+///
+/// Key prefixes steer where a write lands once persisted: `app:` and
+/// `user:` scope the key to the app/user tier instead of the session, and
+/// `temp:` keys are visible for the rest of the turn but are stripped
+/// before the delta is persisted.
+///
+/// A `State()` built directly by Python code (not handed out by a
+/// context) isn't attached to any turn, so it writes straight through
+/// instead of batching.
+#[pyclass(name = "State")]
+#[derive(Clone)]
+pub struct PyState {
+    base: HashMap<String, serde_json::Value>,
+    pending_delta: HashMap<String, serde_json::Value>,
+    tracks_delta: bool,
+}
+
+impl PyState {
+    fn merged(&self) -> HashMap<String, serde_json::Value> {
+        let mut merged = self.base.clone();
+        for (key, value) in &self.pending_delta {
+            if value.is_null() {
+                merged.remove(key);
+            } else {
+                merged.insert(key.clone(), value.clone());
+            }
+        }
+        merged
+    }
+}
+
+#[pymethods]
+impl PyState {
+    #[new]
+    fn new() -> Self {
+        Self {
+            base: HashMap::new(),
+            pending_delta: HashMap::new(),
+            tracks_delta: false,
+        }
+    }
+
+    fn get(&self, py: Python<'_>, key: &str) -> PyObject {
+        let value = match self.pending_delta.get(key) {
+            Some(value) if value.is_null() => return py.None(),
+            Some(value) => Some(value),
+            None => self.base.get(key),
+        };
+        match value {
+            Some(value) => pythonize::pythonize(py, value)
+                .map(|b| b.into())
+                .unwrap_or_else(|_| py.None()),
+            None => py.None(),
+        }
+    }
+
+    fn set(&mut self, key: String, value: &Bound<'_, PyAny>) -> PyResult<()> {
+        let json_value: serde_json::Value = pythonize::depythonize(value)
+            .map_err(|e| crate::error::SerializationError::new_err(e.to_string()))?;
+        if self.tracks_delta {
+            self.pending_delta.insert(key, json_value);
+        } else {
+            self.base.insert(key, json_value);
+        }
+        Ok(())
+    }
+
+    fn all(&self, py: Python<'_>) -> PyObject {
+        pythonize::pythonize(py, &self.merged())
+            .map(|b| b.into())
+            .unwrap_or_else(|_| py.None())
+    }
+
+    fn contains(&self, key: &str) -> bool {
+        match self.pending_delta.get(key) {
+            Some(value) => !value.is_null(),
+            None => self.base.contains_key(key),
+        }
+    }
+
+    fn remove(&mut self, key: &str) -> bool {
+        if self.tracks_delta {
+            let existed = self.contains(key);
+            if existed {
+                self.pending_delta
+                    .insert(key.to_string(), serde_json::Value::Null);
+            }
+            existed
+        } else {
+            self.base.remove(key).is_some()
+        }
+    }
+
+    fn keys(&self) -> Vec<String> {
+        self.merged().into_keys().collect()
+    }
+
+    /// The writes recorded on this state so far this turn, keyed exactly as
+    /// they were set (prefixes included), with removed keys mapped to
+    /// `None`. Mirrors what the runner drains into the emitted event's
+    /// `state_delta`, before `temp:` keys are stripped.
+    fn pending_delta(&self, py: Python<'_>) -> PyObject {
+        pythonize::pythonize(py, &self.pending_delta)
+            .map(|b| b.into())
+            .unwrap_or_else(|_| py.None())
+    }
+
+    fn __len__(&self) -> usize {
+        self.merged().len()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("State(keys={})", self.merged().len())
+    }
+}
+
+impl PyState {
+    /// Create an empty, delta-tracking state - used where a context has no
+    /// upstream session to snapshot from, but writes still need to be
+    /// captured so they reach the emitted event.
+    pub fn empty() -> Self {
+        Self {
+            base: HashMap::new(),
+            pending_delta: HashMap::new(),
+            tracks_delta: true,
+        }
+    }
+
+    /// Create a delta-tracking state snapshotting adk_core::State
+    pub fn from_core_state(state: &dyn adk_core::State) -> Self {
+        Self {
+            base: state.all(),
+            pending_delta: HashMap::new(),
+            tracks_delta: true,
+        }
+    }
+
+    /// Create a delta-tracking state snapshotting adk_session::State
+    pub fn from_session_state(state: &dyn adk_session::State) -> Self {
+        Self {
+            base: state.all(),
+            pending_delta: HashMap::new(),
+            tracks_delta: true,
+        }
+    }
+
+    /// The pending delta recorded on a context-owned state, with `temp:`
+    /// keys stripped - what the runner attaches to the emitted event's
+    /// `state_delta` for session services to replay.
+    pub fn persistable_delta(&self) -> HashMap<String, serde_json::Value> {
+        self.pending_delta
+            .iter()
+            .filter(|(key, _)| !key.starts_with("temp:"))
+            .map(|(key, value)| (key.clone(), value.clone()))
+            .collect()
+    }
+}
+
+/// Request to create a new session
+#[pyclass(name = "CreateSessionRequest")]
+#[derive(Clone)]
+pub struct PyCreateSessionRequest {
+    pub(crate) app_name: String,
+    pub(crate) user_id: String,
+    pub(crate) session_id: Option<String>,
+    pub(crate) state: HashMap<String, serde_json::Value>,
+}
+
+#[pymethods]
+impl PyCreateSessionRequest {
+    #[new]
+    #[pyo3(signature = (app_name, user_id, session_id=None))]
+    fn new(app_name: String, user_id: String, session_id: Option<String>) -> Self {
+        Self {
+            app_name,
+            user_id,
+            session_id,
+            state: HashMap::new(),
+        }
+    }
+
+    #[getter]
+    fn app_name(&self) -> &str {
+        &self.app_name
+    }
+
+    #[getter]
+    fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    #[getter]
+    fn session_id(&self) -> Option<&str> {
+        self.session_id.as_deref()
+    }
+
+    fn with_state<'a>(
+        mut slf: PyRefMut<'a, Self>,
+        key: String,
+        value: &Bound<'a, PyAny>,
+    ) -> PyResult<PyRefMut<'a, Self>> {
+        let json_value: serde_json::Value = pythonize::depythonize(value)
+            .map_err(|e| crate::error::SerializationError::new_err(e.to_string()))?;
+        slf.state.insert(key, json_value);
+        Ok(slf)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "CreateSessionRequest(app='{}', user='{}', session={:?})",
+            self.app_name, self.user_id, self.session_id
+        )
+    }
+}
+
+/// Request to get an existing session
+#[pyclass(name = "GetSessionRequest")]
+#[derive(Clone)]
+pub struct PyGetSessionRequest {
+    pub(crate) app_name: String,
+    pub(crate) user_id: String,
+    pub(crate) session_id: String,
+    pub(crate) num_recent_events: Option<usize>,
+    pub(crate) after: Option<DateTime<Utc>>,
+}
+
+#[pymethods]
+impl PyGetSessionRequest {
+    #[new]
+    #[pyo3(signature = (app_name, user_id, session_id, num_recent_events=None))]
+    fn new(
+        app_name: String,
+        user_id: String,
+        session_id: String,
+        num_recent_events: Option<usize>,
+    ) -> Self {
+        Self {
+            app_name,
+            user_id,
+            session_id,
+            num_recent_events,
+            after: None,
+        }
+    }
+
+    #[getter]
+    fn app_name(&self) -> &str {
+        &self.app_name
+    }
+
+    #[getter]
+    fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    #[getter]
+    fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    #[getter]
+    fn num_recent_events(&self) -> Option<usize> {
+        self.num_recent_events
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "GetSessionRequest(app='{}', user='{}', session='{}')",
+            self.app_name, self.user_id, self.session_id
+        )
+    }
+}
+
+/// Request to list sessions for a user, optionally filtered and paginated.
+///
+/// `modified_after`/`modified_before` bound `last_update_time` (RFC 3339
+/// strings), `with_state_contains` keeps only sessions whose state has a
+/// matching key/value, and `limit`/`page_token` page through the (filtered)
+/// result set in ascending `(last_update_time, id)` order. `page_token` is
+/// whatever `SessionPage.next_page_token` returned - treat it as opaque.
+#[pyclass(name = "ListSessionRequest")]
+#[derive(Clone)]
+pub struct PyListSessionRequest {
+    pub(crate) app_name: String,
+    pub(crate) user_id: String,
+    pub(crate) modified_after: Option<DateTime<Utc>>,
+    pub(crate) modified_before: Option<DateTime<Utc>>,
+    pub(crate) state_contains: Option<(String, serde_json::Value)>,
+    pub(crate) limit: Option<usize>,
+    pub(crate) page_token: Option<String>,
+}
+
+#[pymethods]
+impl PyListSessionRequest {
+    #[new]
+    #[pyo3(signature = (app_name, user_id, modified_after=None, modified_before=None, limit=None, page_token=None))]
+    fn new(
+        app_name: String,
+        user_id: String,
+        modified_after: Option<String>,
+        modified_before: Option<String>,
+        limit: Option<usize>,
+        page_token: Option<String>,
+    ) -> PyResult<Self> {
+        Ok(Self {
+            app_name,
+            user_id,
+            modified_after: parse_rfc3339(modified_after)?,
+            modified_before: parse_rfc3339(modified_before)?,
+            state_contains: None,
+            limit,
+            page_token,
+        })
+    }
+
+    #[getter]
+    fn app_name(&self) -> &str {
+        &self.app_name
+    }
+
+    #[getter]
+    fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    /// Keep only sessions whose state has `key` set to `value`.
+    fn with_state_contains<'a>(
+        mut slf: PyRefMut<'a, Self>,
+        key: String,
+        value: &Bound<'a, PyAny>,
+    ) -> PyResult<PyRefMut<'a, Self>> {
+        let json_value: serde_json::Value = pythonize::depythonize(value)
+            .map_err(|e| crate::error::SerializationError::new_err(e.to_string()))?;
+        slf.state_contains = Some((key, json_value));
+        Ok(slf)
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "ListSessionRequest(app='{}', user='{}', limit={:?})",
+            self.app_name, self.user_id, self.limit
+        )
+    }
+}
+
+fn parse_rfc3339(value: Option<String>) -> PyResult<Option<DateTime<Utc>>> {
+    value
+        .map(|s| {
+            DateTime::parse_from_rfc3339(&s)
+                .map(|t| t.with_timezone(&Utc))
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))
+        })
+        .transpose()
+}
+
+/// One page of `InMemorySessionService.list()` results.
+#[pyclass(name = "SessionPage")]
+#[derive(Clone)]
+pub struct PySessionPage {
+    sessions: Vec<PySession>,
+    next_page_token: Option<String>,
+}
+
+#[pymethods]
+impl PySessionPage {
+    /// The sessions in this page.
+    #[getter]
+    fn sessions(&self) -> Vec<PySession> {
+        self.sessions.clone()
+    }
+
+    /// Cursor to pass as `page_token` on the next `ListSessionRequest` to
+    /// continue after this page, or `None` if this was the last page.
+    #[getter]
+    fn next_page_token(&self) -> Option<String> {
+        self.next_page_token.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "SessionPage(sessions={}, next_page_token={:?})",
+            self.sessions.len(),
+            self.next_page_token
+        )
+    }
+}
+
+fn encode_page_token(last_update_time: DateTime<Utc>, id: &str) -> String {
+    format!("{}|{}", last_update_time.to_rfc3339(), id)
+}
+
+fn decode_page_token(token: &str) -> PyResult<(DateTime<Utc>, &str)> {
+    let (time_part, id_part) = token
+        .split_once('|')
+        .ok_or_else(|| pyo3::exceptions::PyValueError::new_err("malformed page_token"))?;
+    let time = DateTime::parse_from_rfc3339(time_part)
+        .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?
+        .with_timezone(&Utc);
+    Ok((time, id_part))
+}
+
+/// Apply `request`'s filters, ordering, and pagination to `sessions`.
+fn paginate_sessions(
+    sessions: Vec<PySession>,
+    request: &PyListSessionRequest,
+) -> PyResult<PySessionPage> {
+    let mut filtered: Vec<PySession> = sessions
+        .into_iter()
+        .filter(|s| {
+            let after_ok = request
+                .modified_after
+                .map(|after| s.last_update_time > after)
+                .unwrap_or(true);
+            let before_ok = request
+                .modified_before
+                .map(|before| s.last_update_time < before)
+                .unwrap_or(true);
+            let state_ok = request
+                .state_contains
+                .as_ref()
+                .map(|(key, value)| s.state.merged().get(key) == Some(value))
+                .unwrap_or(true);
+            after_ok && before_ok && state_ok
+        })
+        .collect();
+
+    filtered.sort_by(|a, b| (a.last_update_time, &a.id).cmp(&(b.last_update_time, &b.id)));
+
+    let start = match &request.page_token {
+        Some(token) => {
+            let (after_time, after_id) = decode_page_token(token)?;
+            filtered
+                .iter()
+                .position(|s| s.last_update_time == after_time && s.id == after_id)
+                .map(|i| i + 1)
+                .unwrap_or(0)
+        }
+        None => 0,
+    };
+
+    let available = filtered.len().saturating_sub(start);
+    let take = request.limit.unwrap_or(available);
+    let page: Vec<PySession> = filtered[start..].iter().take(take).cloned().collect();
+    let next_page_token = if page.len() < available {
+        page.last()
+            .map(|s| encode_page_token(s.last_update_time, &s.id))
+    } else {
+        None
+    };
+
+    Ok(PySessionPage {
+        sessions: page,
+        next_page_token,
+    })
+}
+
+/// Request to delete a session
+#[pyclass(name = "DeleteSessionRequest")]
+#[derive(Clone)]
+pub struct PyDeleteSessionRequest {
+    pub(crate) app_name: String,
+    pub(crate) user_id: String,
+    pub(crate) session_id: String,
+}
+
+#[pymethods]
+impl PyDeleteSessionRequest {
+    #[new]
+    fn new(app_name: String, user_id: String, session_id: String) -> Self {
+        Self {
+            app_name,
+            user_id,
+            session_id,
+        }
+    }
+
+    #[getter]
+    fn app_name(&self) -> &str {
+        &self.app_name
+    }
+
+    #[getter]
+    fn user_id(&self) -> &str {
+        &self.user_id
+    }
+
+    #[getter]
+    fn session_id(&self) -> &str {
+        &self.session_id
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "DeleteSessionRequest(app='{}', user='{}', session='{}')",
+            self.app_name, self.user_id, self.session_id
+        )
+    }
+}
+
+/// Streaming mode for agent execution
+#[pyclass(name = "StreamingMode", eq, eq_int)]
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum PyStreamingMode {
+    None = 0,
+    SSE = 1,
+    Bidi = 2,
+}
+
+/// Run configuration for agent execution
+///
+/// `resumable`/`resumption_token` only affect `Runner.run_stream()`: when
+/// `resumable` is set and `resumption_token` holds a value previously read
+/// off an `EventStream.resumption_token()`, the next `run_stream()` call
+/// replays any session events recorded after that point before resuming the
+/// live stream, so a client that lost its connection mid-conversation
+/// doesn't miss events emitted while it was disconnected.
+#[pyclass(name = "RunConfig")]
+#[derive(Clone)]
+pub struct PyRunConfig {
+    pub(crate) streaming_mode: PyStreamingMode,
+    pub(crate) resumable: bool,
+    pub(crate) resumption_token: Option<String>,
+}
+
+#[pymethods]
+impl PyRunConfig {
+    #[new]
+    #[pyo3(signature = (streaming_mode=PyStreamingMode::SSE, resumable=false, resumption_token=None))]
+    fn new(
+        streaming_mode: PyStreamingMode,
+        resumable: bool,
+        resumption_token: Option<String>,
+    ) -> Self {
+        Self {
+            streaming_mode,
+            resumable,
+            resumption_token,
+        }
+    }
+
+    #[getter]
+    fn streaming_mode(&self) -> PyStreamingMode {
+        self.streaming_mode
+    }
+
+    #[getter]
+    fn resumable(&self) -> bool {
+        self.resumable
+    }
+
+    #[getter]
+    fn resumption_token(&self) -> Option<String> {
+        self.resumption_token.clone()
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "RunConfig(streaming_mode={:?}, resumable={}, resumption_token={:?})",
+            self.streaming_mode, self.resumable, self.resumption_token
+        )
+    }
+}
+
+impl From<PyRunConfig> for adk_core::RunConfig {
+    fn from(config: PyRunConfig) -> Self {
+        let mode = match config.streaming_mode {
+            PyStreamingMode::None => adk_core::StreamingMode::None,
+            PyStreamingMode::SSE => adk_core::StreamingMode::SSE,
+            PyStreamingMode::Bidi => adk_core::StreamingMode::Bidi,
+        };
+        adk_core::RunConfig {
+            streaming_mode: mode,
+        }
+    }
+}
+
+/// Model generation configuration
+///
+/// Controls LLM generation parameters like temperature, top_p, etc.
+#[pyclass(name = "GenerateContentConfig")]
+#[derive(Clone, Default)]
+pub struct PyGenerateContentConfig {
+    #[pyo3(get, set)]
+    pub temperature: Option<f32>,
+    #[pyo3(get, set)]
+    pub top_p: Option<f32>,
+    #[pyo3(get, set)]
+    pub top_k: Option<i32>,
+    #[pyo3(get, set)]
+    pub max_output_tokens: Option<i32>,
+    response_schema: Option<serde_json::Value>,
+}
+
+#[pymethods]
+impl PyGenerateContentConfig {
+    #[new]
+    #[pyo3(signature = (temperature=None, top_p=None, top_k=None, max_output_tokens=None, response_schema=None))]
+    fn new(
+        temperature: Option<f32>,
+        top_p: Option<f32>,
+        top_k: Option<i32>,
+        max_output_tokens: Option<i32>,
+        response_schema: Option<&pyo3::Bound<'_, pyo3::types::PyDict>>,
+    ) -> PyResult<Self> {
+        let schema = if let Some(dict) = response_schema {
+            Some(
+                pythonize::depythonize::<serde_json::Value>(dict.as_any())
+                    .map_err(|e| crate::error::SerializationError::new_err(e.to_string()))?,
+            )
+        } else {
+            None
+        };
+
+        Ok(Self {
+            temperature,
+            top_p,
+            top_k,
+            max_output_tokens,
+            response_schema: schema,
+        })
+    }
+
+    /// Get response schema as a Python dict
+    #[getter]
+    fn response_schema(&self, py: pyo3::Python<'_>) -> pyo3::PyObject {
+        match &self.response_schema {
+            Some(schema) => pythonize::pythonize(py, schema)
+                .map(|b| b.into())
+                .unwrap_or_else(|_| py.None()),
+            None => py.None(),
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "GenerateContentConfig(temperature={:?}, top_p={:?}, top_k={:?}, max_output_tokens={:?})",
+            self.temperature, self.top_p, self.top_k, self.max_output_tokens
+        )
+    }
+}
+
+impl From<PyGenerateContentConfig> for adk_core::GenerateContentConfig {
+    fn from(config: PyGenerateContentConfig) -> Self {
+        Self {
+            temperature: config.temperature,
+            top_p: config.top_p,
+            top_k: config.top_k,
+            max_output_tokens: config.max_output_tokens,
+            response_schema: config.response_schema,
+        }
+    }
+}
+
+impl From<adk_core::GenerateContentConfig> for PyGenerateContentConfig {
+    fn from(config: adk_core::GenerateContentConfig) -> Self {
+        Self {
+            temperature: config.temperature,
+            top_p: config.top_p,
+            top_k: config.top_k,
+            max_output_tokens: config.max_output_tokens,
+            response_schema: config.response_schema,
+        }
+    }
+}
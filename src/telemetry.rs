@@ -0,0 +1,582 @@
+//! Tracing/observability for agent execution
+//!
+//! Opens a span per invocation carrying `invocation_id`, `agent_name`,
+//! `app_name`, and `session_id` - the same fields already surfaced on
+//! `Context` - with nested child spans per model call and per tool call
+//! recording latency and (for tool calls, where the arguments are actually
+//! available to us) the call's arguments.
+//!
+//! Tracing is off by default and adds no overhead until a caller installs a
+//! sink with `enable_tracing()`, forwarding each completed span to either a
+//! user-supplied Python callable or an OTLP collector.
+//!
+//! `init()` is a separate, lower-level bridge: it forwards raw `tracing`
+//! events (the `tracing::info!`/`debug!`/etc. calls made throughout the
+//! runner and the underlying `adk_*` crates) to a Python callback, for
+//! plain log-style debugging rather than structured spans.
+
+use adk_core::{
+    AfterAgentCallback, AfterModelCallback, BeforeAgentCallback, BeforeModelCallback,
+    BeforeModelResult, CallbackContext, Content, LlmRequest, LlmResponse,
+};
+use opentelemetry::trace::{Span as _, Status, Tracer};
+use opentelemetry::{global, KeyValue};
+use opentelemetry_otlp::WithExportConfig;
+use pyo3::prelude::*;
+use std::collections::HashMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, OnceLock};
+use std::time::{Duration, Instant};
+use tracing::field::{Field, Visit};
+use tracing::{Event, Level, Subscriber};
+use tracing_subscriber::layer::{Context as LayerContext, SubscriberExt};
+use tracing_subscriber::Layer;
+
+/// Which phase of execution a span covers.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum SpanKindLabel {
+    Agent,
+    Model,
+    Tool,
+}
+
+impl SpanKindLabel {
+    fn as_str(self) -> &'static str {
+        match self {
+            Self::Agent => "agent",
+            Self::Model => "model",
+            Self::Tool => "tool",
+        }
+    }
+}
+
+/// A completed span, ready to hand to whichever sink `enable_tracing`
+/// installed.
+#[derive(Clone, Debug)]
+struct SpanRecord {
+    kind: SpanKindLabel,
+    name: String,
+    invocation_id: String,
+    agent_name: String,
+    app_name: String,
+    session_id: String,
+    duration: Duration,
+    attributes: HashMap<String, serde_json::Value>,
+    error: Option<String>,
+}
+
+/// A completed span handed to the Python callback installed via
+/// `enable_tracing(callback=...)`.
+#[pyclass(name = "Span")]
+#[derive(Clone)]
+pub struct PySpan {
+    inner: SpanRecord,
+}
+
+#[pymethods]
+impl PySpan {
+    #[getter]
+    fn kind(&self) -> &str {
+        self.inner.kind.as_str()
+    }
+
+    #[getter]
+    fn name(&self) -> &str {
+        &self.inner.name
+    }
+
+    #[getter]
+    fn invocation_id(&self) -> &str {
+        &self.inner.invocation_id
+    }
+
+    #[getter]
+    fn agent_name(&self) -> &str {
+        &self.inner.agent_name
+    }
+
+    #[getter]
+    fn app_name(&self) -> &str {
+        &self.inner.app_name
+    }
+
+    #[getter]
+    fn session_id(&self) -> &str {
+        &self.inner.session_id
+    }
+
+    #[getter]
+    fn duration_ms(&self) -> f64 {
+        self.inner.duration.as_secs_f64() * 1000.0
+    }
+
+    #[getter]
+    fn error(&self) -> Option<String> {
+        self.inner.error.clone()
+    }
+
+    fn attributes(&self, py: Python<'_>) -> PyResult<PyObject> {
+        let bound = pythonize::pythonize(py, &self.inner.attributes)
+            .map_err(|e| pyo3::exceptions::PyRuntimeError::new_err(e.to_string()))?;
+        Ok(bound.into())
+    }
+
+    fn __repr__(&self) -> String {
+        format!(
+            "Span(kind='{}', name='{}', agent='{}', duration_ms={:.2})",
+            self.inner.kind.as_str(),
+            self.inner.name,
+            self.inner.agent_name,
+            self.duration_ms()
+        )
+    }
+}
+
+/// Holds a Python callback safely across the worker threads spans are
+/// recorded from.
+struct PythonSpanSink {
+    callback: Py<PyAny>,
+}
+
+unsafe impl Send for PythonSpanSink {}
+unsafe impl Sync for PythonSpanSink {}
+
+impl PythonSpanSink {
+    fn dispatch(&self, span: SpanRecord) {
+        Python::with_gil(|py| {
+            let callback = self.callback.clone_ref(py);
+            let py_span = PySpan { inner: span };
+            if let Err(e) = callback.call1(py, (py_span,)) {
+                e.print(py);
+            }
+        });
+    }
+}
+
+enum TracingSink {
+    Callback(PythonSpanSink),
+    Otlp(global::BoxedTracer),
+}
+
+fn sink() -> &'static Mutex<Option<TracingSink>> {
+    static SINK: OnceLock<Mutex<Option<TracingSink>>> = OnceLock::new();
+    SINK.get_or_init(|| Mutex::new(None))
+}
+
+/// In-flight model/tool spans, keyed by `"{kind}:{invocation_id}"`. Calls
+/// within one invocation are not run concurrently today, so a LIFO stack is
+/// enough to pair each `begin` with its matching `end`.
+fn open_spans() -> &'static Mutex<HashMap<String, Vec<(Instant, String)>>> {
+    static OPEN: OnceLock<Mutex<HashMap<String, Vec<(Instant, String)>>>> = OnceLock::new();
+    OPEN.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+fn is_enabled() -> bool {
+    sink().lock().unwrap().is_some()
+}
+
+fn begin(kind: SpanKindLabel, invocation_id: &str, name: &str) {
+    if !is_enabled() {
+        return;
+    }
+    let key = format!("{}:{}", kind.as_str(), invocation_id);
+    open_spans()
+        .lock()
+        .unwrap()
+        .entry(key)
+        .or_default()
+        .push((Instant::now(), name.to_string()));
+}
+
+fn record(
+    kind: SpanKindLabel,
+    ctx: &dyn CallbackContext,
+    attributes: HashMap<String, serde_json::Value>,
+    error: Option<String>,
+) {
+    let key = format!("{}:{}", kind.as_str(), ctx.invocation_id());
+    let Some((start, name)) = open_spans()
+        .lock()
+        .unwrap()
+        .get_mut(&key)
+        .and_then(Vec::pop)
+    else {
+        return;
+    };
+
+    let span = SpanRecord {
+        kind,
+        name,
+        invocation_id: ctx.invocation_id().to_string(),
+        agent_name: ctx.agent_name().to_string(),
+        app_name: ctx.app_name().to_string(),
+        session_id: ctx.session_id().to_string(),
+        duration: start.elapsed(),
+        attributes,
+        error,
+    };
+
+    dispatch(span);
+}
+
+fn dispatch(span: SpanRecord) {
+    let guard = sink().lock().unwrap();
+    match guard.as_ref() {
+        Some(TracingSink::Callback(cb)) => cb.dispatch(span),
+        Some(TracingSink::Otlp(tracer)) => export_otlp(tracer, span),
+        None => {}
+    }
+}
+
+fn export_otlp(tracer: &global::BoxedTracer, span: SpanRecord) {
+    let end = std::time::SystemTime::now();
+    let start = end - span.duration;
+
+    let mut attributes = vec![
+        KeyValue::new("adk.span_kind", span.kind.as_str()),
+        KeyValue::new("adk.invocation_id", span.invocation_id.clone()),
+        KeyValue::new("adk.agent_name", span.agent_name.clone()),
+        KeyValue::new("adk.app_name", span.app_name.clone()),
+        KeyValue::new("adk.session_id", span.session_id.clone()),
+    ];
+    attributes.extend(
+        span.attributes
+            .iter()
+            .map(|(key, value)| KeyValue::new(key.clone(), value.to_string())),
+    );
+
+    let builder = tracer
+        .span_builder(span.name.clone())
+        .with_start_time(start)
+        .with_end_time(end)
+        .with_attributes(attributes);
+
+    let mut otel_span = tracer.build(builder);
+    if let Some(error) = &span.error {
+        otel_span.set_status(Status::error(error.clone()));
+    }
+    otel_span.end();
+}
+
+/// A tool's call directly provides its own name, arguments, and timing -
+/// unlike agent/model spans, no `begin`/`end` pairing is needed since the
+/// call happens synchronously under the binding's control. Called from
+/// `FunctionTool::execute` around the handler invocation.
+pub(crate) fn record_tool_span(
+    ctx_fields: (&str, &str, &str, &str),
+    tool_name: &str,
+    args: &serde_json::Value,
+    duration: Duration,
+    error: Option<String>,
+) {
+    if !is_enabled() {
+        return;
+    }
+    let (invocation_id, agent_name, app_name, session_id) = ctx_fields;
+    let mut attributes = HashMap::new();
+    attributes.insert("tool_args".to_string(), args.clone());
+
+    dispatch(SpanRecord {
+        kind: SpanKindLabel::Tool,
+        name: tool_name.to_string(),
+        invocation_id: invocation_id.to_string(),
+        agent_name: agent_name.to_string(),
+        app_name: app_name.to_string(),
+        session_id: session_id.to_string(),
+        duration,
+        attributes,
+        error,
+    });
+}
+
+/// Agent-level span covering one agent's `run()`. Installed unconditionally
+/// on every `LlmAgent` build alongside whatever callbacks the
+/// caller registered, so tracing works whether or not they use callbacks.
+pub fn before_agent_span_callback() -> BeforeAgentCallback {
+    Box::new(
+        move |ctx: Arc<dyn CallbackContext>| -> Pin<
+            Box<dyn Future<Output = adk_core::Result<Option<Content>>> + Send>,
+        > {
+            Box::pin(async move {
+                begin(SpanKindLabel::Agent, ctx.invocation_id(), ctx.agent_name());
+                Ok(None)
+            })
+        },
+    )
+}
+
+pub fn after_agent_span_callback() -> AfterAgentCallback {
+    Box::new(
+        move |ctx: Arc<dyn CallbackContext>| -> Pin<
+            Box<dyn Future<Output = adk_core::Result<Option<Content>>> + Send>,
+        > {
+            Box::pin(async move {
+                record(SpanKindLabel::Agent, ctx.as_ref(), HashMap::new(), None);
+                Ok(None)
+            })
+        },
+    )
+}
+
+/// Model-call span. `before_model` carries the request's model name (used
+/// as the span name); `after_model` carries whatever the response exposes.
+/// Token counts aren't recorded here because `LlmResponse` doesn't surface
+/// usage information through this binding yet - add an `attributes` entry
+/// for it once that lands.
+pub fn before_model_span_callback() -> BeforeModelCallback {
+    Box::new(
+        move |ctx: Arc<dyn CallbackContext>,
+              request: LlmRequest|
+              -> Pin<Box<dyn Future<Output = adk_core::Result<BeforeModelResult>> + Send>> {
+            Box::pin(async move {
+                begin(SpanKindLabel::Model, ctx.invocation_id(), &request.model);
+                Ok(BeforeModelResult::Continue(request))
+            })
+        },
+    )
+}
+
+pub fn after_model_span_callback() -> AfterModelCallback {
+    Box::new(
+        move |ctx: Arc<dyn CallbackContext>,
+              response: LlmResponse|
+              -> Pin<Box<dyn Future<Output = adk_core::Result<Option<LlmResponse>>> + Send>> {
+            Box::pin(async move {
+                let mut attributes = HashMap::new();
+                if let Some(content) = &response.content {
+                    let chars: usize = content
+                        .parts
+                        .iter()
+                        .filter_map(|p| p.text())
+                        .map(|t| t.chars().count())
+                        .sum();
+                    attributes.insert("response_chars".to_string(), chars.into());
+                }
+                record(SpanKindLabel::Model, ctx.as_ref(), attributes, None);
+                Ok(None)
+            })
+        },
+    )
+}
+
+/// Installs (or replaces) the tracing sink.
+///
+/// Exactly one of `callback` or `otlp_endpoint` should be given: with
+/// `callback`, every completed span is handed to it as a `Span`; with
+/// `otlp_endpoint`, spans are exported as OTLP spans to a collector at that
+/// endpoint (e.g. `"http://localhost:4317"`). Call with no arguments to
+/// disable tracing again.
+#[pyfunction]
+#[pyo3(signature = (callback=None, otlp_endpoint=None, service_name="adk-rust-python"))]
+pub fn enable_tracing(
+    callback: Option<Py<PyAny>>,
+    otlp_endpoint: Option<String>,
+    service_name: &str,
+) -> PyResult<()> {
+    let new_sink = match (callback, otlp_endpoint) {
+        (Some(callback), None) => Some(TracingSink::Callback(PythonSpanSink { callback })),
+        (None, Some(endpoint)) => {
+            let provider = opentelemetry_otlp::new_pipeline()
+                .tracing()
+                .with_exporter(
+                    opentelemetry_otlp::new_exporter()
+                        .tonic()
+                        .with_endpoint(endpoint),
+                )
+                .with_trace_config(opentelemetry_sdk::trace::Config::default().with_resource(
+                    opentelemetry_sdk::Resource::new(vec![KeyValue::new(
+                        "service.name",
+                        service_name.to_string(),
+                    )]),
+                ))
+                .install_batch(opentelemetry_sdk::runtime::Tokio)
+                .map_err(|e| {
+                    pyo3::exceptions::PyRuntimeError::new_err(format!(
+                        "failed to start OTLP exporter: {e}"
+                    ))
+                })?;
+            global::set_tracer_provider(provider);
+            Some(TracingSink::Otlp(global::tracer(service_name.to_string())))
+        }
+        (None, None) => None,
+        (Some(_), Some(_)) => {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "enable_tracing() takes either callback or otlp_endpoint, not both",
+            ));
+        }
+    };
+
+    *sink().lock().unwrap() = new_sink;
+    Ok(())
+}
+
+/// A single formatted `tracing` event, ready to hand to the Python callback
+/// installed via `init()`.
+struct LogRecord {
+    level: &'static str,
+    target: String,
+    message: String,
+}
+
+/// Pulls the `message` field out of a `tracing::Event`; other fields aren't
+/// forwarded today since none of the crate's own `tracing` calls use them,
+/// but the layer is the only place that would need to change if they start.
+struct MessageVisitor {
+    message: String,
+}
+
+impl Visit for MessageVisitor {
+    fn record_debug(&mut self, field: &Field, value: &dyn std::fmt::Debug) {
+        if field.name() == "message" {
+            self.message = format!("{value:?}");
+        }
+    }
+}
+
+fn log_level() -> &'static Mutex<Level> {
+    static LEVEL: OnceLock<Mutex<Level>> = OnceLock::new();
+    LEVEL.get_or_init(|| Mutex::new(Level::INFO))
+}
+
+/// The currently attached `init()` callback's channel, tagged with a
+/// generation counter so a detached (or superseded) `LoggingHandle` can tell
+/// whether it's still the one installed before clearing the slot.
+fn log_sink_slot() -> &'static Mutex<Option<(u64, tokio::sync::mpsc::UnboundedSender<LogRecord>)>> {
+    static SLOT: OnceLock<Mutex<Option<(u64, tokio::sync::mpsc::UnboundedSender<LogRecord>)>>> =
+        OnceLock::new();
+    SLOT.get_or_init(|| Mutex::new(None))
+}
+
+fn next_log_generation() -> u64 {
+    static GENERATION: OnceLock<AtomicU64> = OnceLock::new();
+    GENERATION
+        .get_or_init(|| AtomicU64::new(0))
+        .fetch_add(1, Ordering::SeqCst)
+        + 1
+}
+
+/// Forwards every `tracing` event at or below `log_level()` onto whichever
+/// channel `init()` last installed. Installed once, globally, independent of
+/// the span sink above: that one exports completed agent/model/tool spans,
+/// this one forwards raw `tracing::info!`/`debug!`/etc. calls from the
+/// runner and the underlying `adk_*` crates.
+struct PyLogLayer;
+
+impl<S: Subscriber> Layer<S> for PyLogLayer {
+    fn on_event(&self, event: &Event<'_>, _ctx: LayerContext<'_, S>) {
+        let metadata = event.metadata();
+        if *metadata.level() > *log_level().lock().unwrap() {
+            return;
+        }
+        let Some((_, sender)) = log_sink_slot().lock().unwrap().clone() else {
+            return;
+        };
+
+        let mut visitor = MessageVisitor {
+            message: String::new(),
+        };
+        event.record(&mut visitor);
+        let _ = sender.send(LogRecord {
+            level: metadata.level().as_str(),
+            target: metadata.target().to_string(),
+            message: visitor.message,
+        });
+    }
+}
+
+fn ensure_log_subscriber_installed() {
+    static INSTALLED: OnceLock<()> = OnceLock::new();
+    INSTALLED.get_or_init(|| {
+        let subscriber = tracing_subscriber::registry().with(PyLogLayer);
+        // Another embedder may already have installed its own global
+        // subscriber first; if so, events just won't reach `init()`'s
+        // callback rather than panicking the process.
+        let _ = tracing::subscriber::set_global_default(subscriber);
+    });
+}
+
+/// Holds a Python callback safely across the background task that invokes
+/// it; mirrors `PythonSpanSink` above but for raw `tracing` events rather
+/// than completed spans.
+struct PythonLogSink {
+    callback: Py<PyAny>,
+}
+
+unsafe impl Send for PythonLogSink {}
+unsafe impl Sync for PythonLogSink {}
+
+impl PythonLogSink {
+    fn dispatch(&self, record: LogRecord) {
+        Python::with_gil(|py| {
+            let callback = self.callback.clone_ref(py);
+            if let Err(e) = callback.call1(py, (record.level, record.target, record.message)) {
+                e.print(py);
+            }
+        });
+    }
+}
+
+/// Keeps an `init()`-installed logger callback alive. Dropping the handle
+/// (or calling `detach()` explicitly) stops forwarding `tracing` events to
+/// it; a later `init()` call installs a new handle without needing this one
+/// dropped first. Doesn't tear down the process-wide `tracing` subscriber
+/// itself - only one ever needs to exist, and `init()` reuses it.
+#[pyclass(name = "LoggingHandle")]
+pub struct PyLoggingHandle {
+    generation: u64,
+}
+
+#[pymethods]
+impl PyLoggingHandle {
+    /// Stop forwarding `tracing` events to this handle's callback. A no-op
+    /// if a later `init()` call has already replaced it.
+    fn detach(&self) {
+        let mut slot = log_sink_slot().lock().unwrap();
+        if matches!(slot.as_ref(), Some((generation, _)) if *generation == self.generation) {
+            *slot = None;
+        }
+    }
+
+    fn __repr__(&self) -> String {
+        "LoggingHandle()".to_string()
+    }
+}
+
+impl Drop for PyLoggingHandle {
+    fn drop(&mut self) {
+        self.detach();
+    }
+}
+
+/// Forwards every `tracing` event (level, target, formatted message) to
+/// `logger_cb`, so tool invocations, model calls, and session writes inside
+/// `PyRunner` are debuggable from Python without recompiling with
+/// env-filter tricks. Events are queued onto an internal channel and
+/// delivered to `logger_cb` from a background task that acquires the GIL
+/// itself, so logging never blocks agent execution.
+///
+/// Pass `debug=True` to also forward `DEBUG`-level events; by default only
+/// `INFO` and above are delivered. Returns a `LoggingHandle` that keeps the
+/// callback attached until it's dropped or `detach()` is called.
+#[pyfunction]
+#[pyo3(signature = (logger_cb, debug=false))]
+pub fn init(logger_cb: Py<PyAny>, debug: bool) -> PyLoggingHandle {
+    ensure_log_subscriber_installed();
+    *log_level().lock().unwrap() = if debug { Level::DEBUG } else { Level::INFO };
+
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel::<LogRecord>();
+    let sink = PythonLogSink {
+        callback: logger_cb,
+    };
+    crate::promise::spawn_gil_free(async move {
+        while let Some(record) = rx.recv().await {
+            sink.dispatch(record);
+        }
+    });
+
+    let generation = next_log_generation();
+    *log_sink_slot().lock().unwrap() = Some((generation, tx));
+    PyLoggingHandle { generation }
+}
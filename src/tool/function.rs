@@ -1,10 +1,24 @@
 //! FunctionTool and BasicToolset - user-defined tools
+//!
+//! Handlers may be plain callables or `async def` coroutines; `PythonTool`
+//! detects which at call time and drives coroutines to completion on the
+//! event loop instead of handing back an un-awaited coroutine object.
+//!
+//! `execute_inner` calls into the handler via `spawn_blocking`, which always
+//! acquires its own GIL on a dedicated thread rather than depending on the
+//! caller's - so a handler that itself schedules async work onto the shared
+//! runtime (see `promise::spawn_gil_free`'s invariant) can't deadlock
+//! waiting for a GIL this task is holding.
 
 use adk_core::{Result as AdkResult, Tool, ToolContext};
 use async_trait::async_trait;
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use serde::Serialize;
+use std::collections::HashMap;
 use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
 
 use crate::context::PyToolContext;
 
@@ -15,16 +29,135 @@ pub struct PyFunctionTool {
     pub(crate) inner: Arc<dyn Tool>,
 }
 
+/// Declarative coercion applied to a single argument before the Python
+/// handler is called, so loosely-typed tool-call JSON ("42", "true") arrives
+/// as the int/float/bool the handler actually expects. Timestamps are
+/// validated and normalized, but still arrive as RFC 3339 strings rather
+/// than `datetime` objects.
+#[derive(Clone, Debug)]
+enum ArgCoercion {
+    AsIs,
+    Int,
+    Float,
+    Bool,
+    Timestamp,
+    TimestampFmt(String),
+}
+
+impl ArgCoercion {
+    /// Parse a conversion name as given in `coercions={"param": "int"}`.
+    fn parse(spec: &str) -> Result<Self, String> {
+        match spec {
+            "bytes" | "string" => Ok(Self::AsIs),
+            "int" => Ok(Self::Int),
+            "float" => Ok(Self::Float),
+            "bool" => Ok(Self::Bool),
+            "timestamp" => Ok(Self::Timestamp),
+            other => match other.strip_prefix("timestamp_fmt:") {
+                Some(fmt) => Ok(Self::TimestampFmt(fmt.to_string())),
+                None => Err(format!(
+                    "unknown coercion '{other}' (expected bytes, string, int, float, bool, timestamp, or timestamp_fmt:<strftime>)"
+                )),
+            },
+        }
+    }
+
+    /// Apply the coercion to a single argument value, producing a `ToolError`
+    /// naming the offending parameter and target type on failure.
+    fn apply(&self, param: &str, value: serde_json::Value) -> AdkResult<serde_json::Value> {
+        use serde_json::Value;
+
+        let fail = |target: &str| -> adk_core::AdkError {
+            adk_core::AdkError::Tool(format!(
+                "argument '{param}' could not be coerced to {target} (got {value})"
+            ))
+        };
+
+        match self {
+            Self::AsIs => Ok(value),
+            Self::Int => match &value {
+                Value::Number(n) if n.is_i64() || n.is_u64() => Ok(value),
+                Value::String(s) => s
+                    .trim()
+                    .parse::<i64>()
+                    .map(Value::from)
+                    .map_err(|_| fail("int")),
+                _ => Err(fail("int")),
+            },
+            Self::Float => match &value {
+                Value::Number(_) => Ok(value),
+                Value::String(s) => s
+                    .trim()
+                    .parse::<f64>()
+                    .ok()
+                    .and_then(serde_json::Number::from_f64)
+                    .map(Value::Number)
+                    .ok_or_else(|| fail("float")),
+                _ => Err(fail("float")),
+            },
+            Self::Bool => match &value {
+                Value::Bool(_) => Ok(value),
+                Value::String(s) => s
+                    .trim()
+                    .to_lowercase()
+                    .parse::<bool>()
+                    .map(Value::Bool)
+                    .map_err(|_| fail("bool")),
+                _ => Err(fail("bool")),
+            },
+            Self::Timestamp => {
+                let s = value.as_str().ok_or_else(|| fail("timestamp"))?;
+                let dt = chrono::DateTime::parse_from_rfc3339(s).map_err(|_| fail("timestamp"))?;
+                Ok(Value::String(dt.to_rfc3339()))
+            }
+            Self::TimestampFmt(fmt) => {
+                let s = value.as_str().ok_or_else(|| fail("timestamp"))?;
+                let naive = chrono::NaiveDateTime::parse_from_str(s, fmt)
+                    .map_err(|_| fail(&format!("timestamp (format '{fmt}')")))?;
+                Ok(Value::String(naive.and_utc().to_rfc3339()))
+            }
+        }
+    }
+}
+
 struct PythonTool {
     name: String,
     description: String,
     handler: Py<PyAny>,
     parameters_schema: Option<serde_json::Value>,
+    coercions: HashMap<String, ArgCoercion>,
 }
 
 unsafe impl Send for PythonTool {}
 unsafe impl Sync for PythonTool {}
 
+impl PythonTool {
+    fn coerce_args(&self, args: serde_json::Value) -> AdkResult<serde_json::Value> {
+        if self.coercions.is_empty() {
+            return Ok(args);
+        }
+        let mut map = match args {
+            serde_json::Value::Object(map) => map,
+            other => return Ok(other),
+        };
+        for (param, coercion) in &self.coercions {
+            if let Some(value) = map.remove(param) {
+                map.insert(param.clone(), coercion.apply(param, value)?);
+            }
+        }
+        Ok(serde_json::Value::Object(map))
+    }
+}
+
+/// What a Python handler call produced, determined before we leave the
+/// `spawn_blocking` thread: either a plain return value, already
+/// depythonized, or a coroutine that still needs to be driven to
+/// completion on the event loop.
+enum HandlerOutcome {
+    Value(serde_json::Value),
+    Awaitable(Py<PyAny>),
+}
+
 #[async_trait]
 impl Tool for PythonTool {
     fn name(&self) -> &str {
@@ -42,10 +175,46 @@ impl Tool for PythonTool {
         ctx: Arc<dyn ToolContext>,
         args: serde_json::Value,
     ) -> AdkResult<serde_json::Value> {
+        let args = self.coerce_args(args)?;
         let handler = Python::with_gil(|py| self.handler.clone_ref(py));
 
-        let result = tokio::task::spawn_blocking(move || {
-            Python::with_gil(|py| {
+        let span_ctx_fields = (
+            ctx.invocation_id().to_string(),
+            ctx.agent_name().to_string(),
+            ctx.app_name().to_string(),
+            ctx.session_id().to_string(),
+        );
+        let span_args = args.clone();
+        let span_start = std::time::Instant::now();
+
+        let result = self.execute_inner(ctx, args, handler).await;
+
+        crate::telemetry::record_tool_span(
+            (
+                &span_ctx_fields.0,
+                &span_ctx_fields.1,
+                &span_ctx_fields.2,
+                &span_ctx_fields.3,
+            ),
+            &self.name,
+            &span_args,
+            span_start.elapsed(),
+            result.as_ref().err().map(|e| e.to_string()),
+        );
+
+        result
+    }
+}
+
+impl PythonTool {
+    async fn execute_inner(
+        &self,
+        ctx: Arc<dyn ToolContext>,
+        args: serde_json::Value,
+        handler: Py<PyAny>,
+    ) -> AdkResult<serde_json::Value> {
+        let outcome = tokio::task::spawn_blocking(move || {
+            Python::with_gil(|py| -> AdkResult<HandlerOutcome> {
                 let py_ctx = PyToolContext {
                     base: crate::context::PyContext::from_readonly(ctx.as_ref()),
                     function_call_id: ctx.function_call_id().to_string(),
@@ -58,26 +227,52 @@ impl Tool for PythonTool {
                     .call1(py, (py_ctx, py_args))
                     .map_err(|e| adk_core::AdkError::Tool(e.to_string()))?;
 
+                // `async def` handlers return an un-awaited coroutine from
+                // `call1`; detect that instead of forcing every handler to
+                // be a plain callable.
+                if result.bind(py).hasattr("__await__").unwrap_or(false) {
+                    return Ok(HandlerOutcome::Awaitable(result));
+                }
+
                 pythonize::depythonize::<serde_json::Value>(result.bind(py))
                     .map_err(|e| adk_core::AdkError::Tool(e.to_string()))
+                    .map(HandlerOutcome::Value)
             })
         })
         .await
         .map_err(|e| adk_core::AdkError::Tool(e.to_string()))??;
 
-        Ok(result)
+        match outcome {
+            HandlerOutcome::Value(value) => Ok(value),
+            HandlerOutcome::Awaitable(coro) => {
+                let future = Python::with_gil(|py| {
+                    pyo3_async_runtimes::tokio::into_future(coro.bind(py).clone())
+                })
+                .map_err(|e| adk_core::AdkError::Tool(e.to_string()))?;
+
+                let awaited = future
+                    .await
+                    .map_err(|e| adk_core::AdkError::Tool(e.to_string()))?;
+
+                Python::with_gil(|py| {
+                    pythonize::depythonize::<serde_json::Value>(awaited.bind(py))
+                        .map_err(|e| adk_core::AdkError::Tool(e.to_string()))
+                })
+            }
+        }
     }
 }
 
 #[pymethods]
 impl PyFunctionTool {
     #[new]
-    #[pyo3(signature = (name, description, handler, parameters_schema=None))]
+    #[pyo3(signature = (name, description, handler, parameters_schema=None, coercions=None))]
     fn new(
         name: String,
         description: String,
         handler: Py<PyAny>,
         parameters_schema: Option<&Bound<'_, PyDict>>,
+        coercions: Option<HashMap<String, String>>,
     ) -> PyResult<Self> {
         let schema = if let Some(schema_dict) = parameters_schema {
             Some(
@@ -88,11 +283,26 @@ impl PyFunctionTool {
             None
         };
 
+        let coercions = coercions
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(param, spec)| {
+                ArgCoercion::parse(&spec)
+                    .map(|c| (param.clone(), c))
+                    .map_err(|e| {
+                        pyo3::exceptions::PyValueError::new_err(format!(
+                            "invalid coercion for '{param}': {e}"
+                        ))
+                    })
+            })
+            .collect::<PyResult<HashMap<_, _>>>()?;
+
         let tool = PythonTool {
             name,
             description,
             handler,
             parameters_schema: schema,
+            coercions,
         };
 
         Ok(Self {
@@ -165,6 +375,69 @@ impl PyMcpToolWrapper {
     }
 }
 
+/// `ToolContext` used for a direct `execute_batch` call, which happens
+/// outside any live agent invocation. Mirrors `mcp::MinimalContext`, extended
+/// with the `function_call_id` that `ToolContext` adds on top of
+/// `ReadonlyContext`.
+struct BatchToolContext {
+    invocation_id: String,
+    function_call_id: String,
+}
+
+impl BatchToolContext {
+    fn new(function_call_id: String) -> Self {
+        Self {
+            invocation_id: uuid::Uuid::new_v4().to_string(),
+            function_call_id,
+        }
+    }
+}
+
+#[async_trait]
+impl adk_core::ReadonlyContext for BatchToolContext {
+    fn invocation_id(&self) -> &str {
+        &self.invocation_id
+    }
+    fn agent_name(&self) -> &str {
+        "basic_toolset"
+    }
+    fn user_id(&self) -> &str {
+        "system"
+    }
+    fn app_name(&self) -> &str {
+        "adk_python"
+    }
+    fn session_id(&self) -> &str {
+        "batch_session"
+    }
+    fn branch(&self) -> &str {
+        "main"
+    }
+    fn user_content(&self) -> &adk_core::Content {
+        static EMPTY_CONTENT: std::sync::OnceLock<adk_core::Content> = std::sync::OnceLock::new();
+        EMPTY_CONTENT.get_or_init(|| adk_core::Content::new("user"))
+    }
+}
+
+#[async_trait]
+impl ToolContext for BatchToolContext {
+    fn function_call_id(&self) -> &str {
+        &self.function_call_id
+    }
+}
+
+/// Per-call outcome of an `execute_batch` call, keyed by tool name so a
+/// caller can match results back up even though they're returned in the
+/// original input order.
+#[derive(Serialize)]
+struct BatchCallOutcome {
+    tool: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+}
+
 /// A collection of tools
 #[pyclass(name = "BasicToolset")]
 pub struct PyBasicToolset {
@@ -194,6 +467,104 @@ impl PyBasicToolset {
         self.tools.clone()
     }
 
+    /// Execute several tool calls concurrently, bounded to the number of
+    /// available CPUs, with each call acquiring the GIL only for its own
+    /// marshalling/handler window so handlers that release the GIL (I/O,
+    /// HTTP requests) actually overlap.
+    ///
+    /// A failing call is isolated to its own outcome instead of aborting
+    /// the rest of the batch, matching how real multi-tool-call LLM turns
+    /// expect independent calls to behave. Results are returned as a list
+    /// of dicts in input order, not completion order: each holds `"tool"`
+    /// plus `"result"` on success or `"error"` on failure.
+    ///
+    /// Args:
+    ///     calls: List of `(tool_name, args)` pairs, `args` being a dict of
+    ///         keyword arguments for the tool.
+    fn execute_batch<'py>(
+        &self,
+        py: Python<'py>,
+        calls: Vec<(String, Bound<'py, PyAny>)>,
+    ) -> PyResult<Bound<'py, PyAny>> {
+        let mut resolved = Vec::with_capacity(calls.len());
+        for (tool_name, args) in calls {
+            let tool = self
+                .tools
+                .iter()
+                .find(|t| t.inner.name() == tool_name)
+                .map(|t| t.inner.clone())
+                .ok_or_else(|| {
+                    pyo3::exceptions::PyKeyError::new_err(format!(
+                        "no such tool in toolset: '{tool_name}'"
+                    ))
+                })?;
+            let args_json: serde_json::Value = pythonize::depythonize(&args)
+                .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+            resolved.push((tool_name, tool, args_json));
+        }
+
+        let max_concurrency = std::thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(4);
+
+        pyo3_async_runtimes::tokio::future_into_py(py, async move {
+            let semaphore = Arc::new(Semaphore::new(max_concurrency.max(1)));
+            let mut set = JoinSet::new();
+
+            for (index, (tool_name, tool, args)) in resolved.into_iter().enumerate() {
+                let semaphore = semaphore.clone();
+                set.spawn(async move {
+                    let _permit = semaphore
+                        .acquire_owned()
+                        .await
+                        .expect("semaphore is never closed");
+
+                    let ctx: Arc<dyn ToolContext> =
+                        Arc::new(BatchToolContext::new(uuid::Uuid::new_v4().to_string()));
+
+                    let outcome = match tool.execute(ctx, args).await {
+                        Ok(result) => BatchCallOutcome {
+                            tool: tool_name,
+                            result: Some(result),
+                            error: None,
+                        },
+                        Err(e) => BatchCallOutcome {
+                            tool: tool_name,
+                            result: None,
+                            error: Some(e.to_string()),
+                        },
+                    };
+                    (index, outcome)
+                });
+            }
+
+            let mut results: Vec<(usize, BatchCallOutcome)> = Vec::with_capacity(set.len());
+            while let Some(joined) = set.join_next().await {
+                let pair = joined.map_err(|e| {
+                    pyo3::exceptions::PyRuntimeError::new_err(format!(
+                        "tool call task panicked: {e}"
+                    ))
+                })?;
+                results.push(pair);
+            }
+            results.sort_by_key(|(index, _)| *index);
+
+            let outcomes: Vec<BatchCallOutcome> =
+                results.into_iter().map(|(_, outcome)| outcome).collect();
+
+            Python::with_gil(|py| {
+                let value = serde_json::to_value(&outcomes).map_err(|e| {
+                    pyo3::exceptions::PyRuntimeError::new_err(format!(
+                        "failed to serialize batch results: {e}"
+                    ))
+                })?;
+                let py_obj = pythonize::pythonize(py, &value)
+                    .map_err(|e| pyo3::exceptions::PyValueError::new_err(e.to_string()))?;
+                Ok(py_obj.unbind())
+            })
+        })
+    }
+
     #[getter]
     fn name(&self) -> String {
         self.name.clone()
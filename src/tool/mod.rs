@@ -8,13 +8,16 @@
 //! - `LoadArtifactsTool` - Load artifacts into context
 //! - `GoogleSearchTool` - Google search (Gemini grounding)
 //! - `McpToolset` - MCP (Model Context Protocol) integration
+//! - `ParallelAgentTool` - Fan out to several sub-agents with bounded concurrency
 
 mod agent_tool;
 mod builtin;
 pub mod function;
 mod mcp;
+mod parallel_agent_tool;
 
 pub use agent_tool::PyAgentTool;
 pub use builtin::{PyExitLoopTool, PyGoogleSearchTool, PyLoadArtifactsTool};
 pub use function::{PyBasicToolset, PyFunctionTool, PyMcpToolWrapper};
 pub use mcp::PyMcpToolset;
+pub use parallel_agent_tool::PyParallelAgentTool;
@@ -0,0 +1,202 @@
+//! ParallelAgentTool - fan out a single call to several sub-agents at once
+
+use adk_core::{Agent, Result as AdkResult, Tool, ToolContext};
+use adk_tool::{AgentTool, AgentToolConfig};
+use async_trait::async_trait;
+use pyo3::prelude::*;
+use serde::Serialize;
+use serde_json::json;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+
+use crate::agent::{PyCustomAgent, PyLlmAgent};
+
+/// Per-agent outcome of a `ParallelAgentTool` call.
+#[derive(Serialize)]
+struct AgentOutcome {
+    agent: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    output: Option<serde_json::Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<String>,
+    timed_out: bool,
+}
+
+/// Runs several wrapped sub-agents concurrently, gated by a `Semaphore` sized
+/// to `max_concurrency`, and collects a per-agent outcome instead of aborting
+/// the whole batch on the first failure.
+struct ParallelAgentTool {
+    name: String,
+    description: String,
+    agents: Vec<(String, Arc<AgentTool>)>,
+    max_concurrency: usize,
+}
+
+#[async_trait]
+impl Tool for ParallelAgentTool {
+    fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn description(&self) -> &str {
+        &self.description
+    }
+
+    fn parameters_schema(&self) -> Option<serde_json::Value> {
+        Some(json!({
+            "type": "object",
+            "properties": {
+                "input": { "type": "string", "description": "Request forwarded to every sub-agent" }
+            },
+            "required": ["input"],
+        }))
+    }
+
+    async fn execute(
+        &self,
+        ctx: Arc<dyn ToolContext>,
+        args: serde_json::Value,
+    ) -> AdkResult<serde_json::Value> {
+        let semaphore = Arc::new(Semaphore::new(self.max_concurrency.max(1)));
+        let mut set = JoinSet::new();
+
+        for (index, (name, tool)) in self.agents.iter().enumerate() {
+            let semaphore = semaphore.clone();
+            let tool = tool.clone();
+            let ctx = ctx.clone();
+            let args = args.clone();
+            let name = name.clone();
+
+            set.spawn(async move {
+                let _permit = semaphore
+                    .acquire_owned()
+                    .await
+                    .expect("semaphore is never closed");
+
+                let outcome = match tool.execute(ctx, args).await {
+                    Ok(output) => AgentOutcome {
+                        agent: name,
+                        output: Some(output),
+                        error: None,
+                        timed_out: false,
+                    },
+                    Err(e) => AgentOutcome {
+                        agent: name,
+                        output: None,
+                        timed_out: matches!(e, adk_core::AdkError::Timeout(_)),
+                        error: Some(e.to_string()),
+                    },
+                };
+                (index, outcome)
+            });
+        }
+
+        let mut results: Vec<(usize, AgentOutcome)> = Vec::with_capacity(self.agents.len());
+        while let Some(joined) = set.join_next().await {
+            let pair = joined
+                .map_err(|e| adk_core::AdkError::Tool(format!("sub-agent task panicked: {e}")))?;
+            results.push(pair);
+        }
+        results.sort_by_key(|(index, _)| *index);
+
+        let outcomes: Vec<AgentOutcome> = results.into_iter().map(|(_, outcome)| outcome).collect();
+        serde_json::to_value(outcomes)
+            .map_err(|e| adk_core::AdkError::Tool(format!("failed to serialize results: {e}")))
+    }
+}
+
+/// Scatter-gather tool that runs several wrapped sub-agents concurrently.
+///
+/// Each agent is wrapped the same way `AgentTool` would wrap it, including
+/// its own `timeout_secs`, so a slow sub-agent times out without blocking
+/// the rest of the batch. Results (or per-agent errors) are returned in the
+/// order the agents were supplied, not completion order.
+#[pyclass(name = "ParallelAgentTool")]
+#[derive(Clone)]
+pub struct PyParallelAgentTool {
+    pub(crate) inner: Arc<dyn Tool>,
+}
+
+#[pymethods]
+impl PyParallelAgentTool {
+    /// Create a ParallelAgentTool.
+    ///
+    /// Args:
+    ///     agents: List of LlmAgent/CustomAgent instances to run concurrently
+    ///     max_concurrency: Maximum number of agents running at once
+    ///     name: Tool name exposed to the model
+    ///     description: Optional tool description
+    ///     skip_summarization: If True, each sub-agent returns raw output
+    ///     timeout_secs: Optional per-agent timeout in seconds
+    #[new]
+    #[pyo3(signature = (agents, max_concurrency=4, name="parallel_agents".to_string(), description=None, skip_summarization=false, timeout_secs=None))]
+    fn new(
+        agents: Vec<Bound<'_, PyAny>>,
+        max_concurrency: usize,
+        name: String,
+        description: Option<String>,
+        skip_summarization: bool,
+        timeout_secs: Option<u64>,
+    ) -> PyResult<Self> {
+        if agents.is_empty() {
+            return Err(pyo3::exceptions::PyValueError::new_err(
+                "ParallelAgentTool requires at least one agent",
+            ));
+        }
+
+        let mut wrapped = Vec::with_capacity(agents.len());
+        for agent in &agents {
+            let agent_arc: Arc<dyn adk_core::Agent> =
+                if let Ok(llm_agent) = agent.extract::<PyRef<'_, PyLlmAgent>>() {
+                    llm_agent.inner.clone()
+                } else if let Ok(custom_agent) = agent.extract::<PyRef<'_, PyCustomAgent>>() {
+                    custom_agent.inner.clone()
+                } else {
+                    return Err(pyo3::exceptions::PyTypeError::new_err(
+                        "agents must be LlmAgent or CustomAgent instances",
+                    ));
+                };
+
+            let agent_name = Agent::name(agent_arc.as_ref()).to_string();
+
+            let mut config = AgentToolConfig::default();
+            config.skip_summarization = skip_summarization;
+            if let Some(secs) = timeout_secs {
+                config.timeout = Some(std::time::Duration::from_secs(secs));
+            }
+
+            wrapped.push((
+                agent_name,
+                Arc::new(AgentTool::with_config(agent_arc, config)),
+            ));
+        }
+
+        let tool = ParallelAgentTool {
+            name,
+            description: description.unwrap_or_else(|| {
+                "Runs several agents concurrently and aggregates their outputs.".to_string()
+            }),
+            agents: wrapped,
+            max_concurrency,
+        };
+
+        Ok(Self {
+            inner: Arc::new(tool),
+        })
+    }
+
+    #[getter]
+    fn name(&self) -> String {
+        self.inner.name().to_string()
+    }
+
+    #[getter]
+    fn description(&self) -> String {
+        self.inner.description().to_string()
+    }
+
+    fn __repr__(&self) -> String {
+        format!("ParallelAgentTool(name='{}')", self.name())
+    }
+}
@@ -1,10 +1,12 @@
 //! Core types exposed to Python
 
 use pyo3::prelude::*;
+use pyo3::types::PyBytes;
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
 
-/// A message part - can be text, function call, or function response
+/// A message part - can be text, function call, function response, or
+/// inline binary data (images, audio, other blobs)
 #[pyclass(name = "Part")]
 #[derive(Clone, Debug, Serialize, Deserialize)]
 pub struct PyPart {
@@ -24,6 +26,10 @@ enum PartInner {
         response: serde_json::Value,
         id: Option<String>,
     },
+    InlineData {
+        mime_type: String,
+        data: Vec<u8>,
+    },
 }
 
 #[pymethods]
@@ -51,6 +57,23 @@ impl PyPart {
         })
     }
 
+    /// Create an inline image part from raw bytes.
+    #[staticmethod]
+    fn image(mime_type: String, data: Vec<u8>) -> Self {
+        Self {
+            inner: PartInner::InlineData { mime_type, data },
+        }
+    }
+
+    /// Create an inline binary blob part (image, audio, or any other raw
+    /// bytes payload) from raw bytes.
+    #[staticmethod]
+    fn blob(mime_type: String, data: Vec<u8>) -> Self {
+        Self {
+            inner: PartInner::InlineData { mime_type, data },
+        }
+    }
+
     /// Check if this is a text part
     fn is_text(&self) -> bool {
         matches!(self.inner, PartInner::Text(_))
@@ -61,6 +84,11 @@ impl PyPart {
         matches!(self.inner, PartInner::FunctionCall { .. })
     }
 
+    /// Check if this is inline binary data (image, audio, other blob)
+    fn is_inline_data(&self) -> bool {
+        matches!(self.inner, PartInner::InlineData { .. })
+    }
+
     /// Get text content (returns None if not a text part)
     fn get_text(&self) -> Option<String> {
         match &self.inner {
@@ -77,6 +105,22 @@ impl PyPart {
         }
     }
 
+    /// Get the MIME type (returns None if not inline data)
+    fn get_mime_type(&self) -> Option<String> {
+        match &self.inner {
+            PartInner::InlineData { mime_type, .. } => Some(mime_type.clone()),
+            _ => None,
+        }
+    }
+
+    /// Get the raw bytes (returns None if not inline data)
+    fn get_bytes<'py>(&self, py: Python<'py>) -> Option<Bound<'py, PyBytes>> {
+        match &self.inner {
+            PartInner::InlineData { data, .. } => Some(PyBytes::new_bound(py, data)),
+            _ => None,
+        }
+    }
+
     fn __repr__(&self) -> String {
         match &self.inner {
             PartInner::Text(s) => format!("Part.text('{}')", s),
@@ -84,6 +128,9 @@ impl PyPart {
             PartInner::FunctionResponse { name, .. } => {
                 format!("Part.function_response('{}')", name)
             }
+            PartInner::InlineData { mime_type, data } => {
+                format!("Part.blob(mime_type='{}', {} bytes)", mime_type, data.len())
+            }
         }
     }
 }
@@ -107,6 +154,9 @@ impl From<adk_core::Part> for PyPart {
                     id,
                 },
             },
+            adk_core::Part::InlineData { mime_type, data } => Self {
+                inner: PartInner::InlineData { mime_type, data },
+            },
             _ => Self {
                 inner: PartInner::Text("[unsupported part type]".to_string()),
             },
@@ -127,6 +177,9 @@ impl From<PyPart> for adk_core::Part {
                     id,
                 }
             }
+            PartInner::InlineData { mime_type, data } => {
+                adk_core::Part::InlineData { mime_type, data }
+            }
         }
     }
 }
@@ -236,6 +289,36 @@ pub struct PyEvent {
 
 #[pymethods]
 impl PyEvent {
+    /// Build an event to append to a session via
+    /// `SessionService.append_event()`. `invocation_id` and `id` are
+    /// generated; `state_delta` follows the `app:`/`user:`/`temp:` key-prefix
+    /// convention documented on `State` and is interpreted by the session
+    /// service the event is appended to.
+    #[new]
+    #[pyo3(signature = (author, content=None, state_delta=None, partial=false, turn_complete=true))]
+    fn new(
+        author: String,
+        content: Option<PyContent>,
+        state_delta: Option<&Bound<'_, pyo3::types::PyDict>>,
+        partial: bool,
+        turn_complete: bool,
+    ) -> PyResult<Self> {
+        let state_delta = match state_delta {
+            Some(dict) => pythonize::depythonize(dict.as_any())
+                .map_err(|e| crate::error::SerializationError::new_err(e.to_string()))?,
+            None => HashMap::new(),
+        };
+        Ok(Self {
+            id: uuid::Uuid::new_v4().to_string(),
+            invocation_id: uuid::Uuid::new_v4().to_string(),
+            author,
+            content,
+            partial,
+            turn_complete,
+            state_delta,
+        })
+    }
+
     /// Get the content if present
     #[getter]
     fn content(&self) -> Option<PyContent> {
@@ -281,3 +364,19 @@ impl From<adk_core::Event> for PyEvent {
         }
     }
 }
+
+impl From<PyEvent> for adk_core::Event {
+    fn from(event: PyEvent) -> Self {
+        let mut core_event = adk_core::Event::new(&event.invocation_id);
+        core_event.id = event.id;
+        core_event.author = event.author;
+        core_event.llm_response = adk_core::LlmResponse {
+            content: event.content.map(adk_core::Content::from),
+            partial: event.partial,
+            turn_complete: event.turn_complete,
+            ..Default::default()
+        };
+        core_event.actions.state_delta = event.state_delta;
+        core_event
+    }
+}